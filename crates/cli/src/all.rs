@@ -0,0 +1,133 @@
+use std::{path::Path, time::Duration};
+
+use anstream::println;
+use anyhow::anyhow;
+use aoc_2024_core::{day::Day, IMPLEMENTED_DAYS};
+use itertools::Itertools;
+
+use crate::{
+    implemented_solver,
+    output::{self, Format},
+    replay::Phases,
+    report::{self, CliError},
+    solve_puzzle_and_print,
+};
+
+/// Solves every implemented day on `<input_dir>/dayN.txt`, days without an
+/// input are skipped. Ends with how long each day took, slowest first.
+pub fn run(input_dir: &Path, format: Format) -> Result<(), CliError> {
+    let mut timings = Vec::new();
+    let mut failures = 0;
+
+    for &day in IMPLEMENTED_DAYS {
+        let input_path = input_dir.join(format!("{day}.txt"));
+        if !input_path.exists() {
+            report::warning(format_args!(
+                "skipping {day}, {} does not exist",
+                input_path.display()
+            ));
+            continue;
+        }
+
+        let solved = implemented_solver(day)
+            .map_err(CliError::from)
+            .and_then(|solver| solve_puzzle_and_print(day, &input_path, solver, format));
+        match solved {
+            Ok((_, _, phases)) => timings.push((day, phases)),
+            Err(err) => {
+                failures += 1;
+                report::report(err, format);
+            }
+        }
+        if format == Format::Plain {
+            println!();
+        }
+    }
+
+    timings.sort_by_key(|(_, phases)| std::cmp::Reverse(phases.total()));
+    print_summary(&timings, format)?;
+
+    if failures > 0 {
+        return Err(anyhow!("{failures} of the days failed").into());
+    }
+    Ok(())
+}
+
+#[derive(Debug, serde::Serialize)]
+struct Summary {
+    days: Vec<DayTiming>,
+    total_ns: u128,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DayTiming {
+    day: u8,
+    read_ns: u128,
+    parse_ns: u128,
+    solve_ns: u128,
+    total_ns: u128,
+}
+
+fn print_summary(timings: &[(Day, Phases)], format: Format) -> anyhow::Result<()> {
+    let total: Duration = timings.iter().map(|(_, phases)| phases.total()).sum();
+
+    if format != Format::Plain {
+        let summary = Summary {
+            days: timings
+                .iter()
+                .map(|(day, phases)| DayTiming {
+                    day: day.number(),
+                    read_ns: phases.read.as_nanos(),
+                    parse_ns: phases.parse.as_nanos(),
+                    solve_ns: phases.solve.as_nanos(),
+                    total_ns: phases.total().as_nanos(),
+                })
+                .collect(),
+            total_ns: total.as_nanos(),
+        };
+        println!(
+            "{}",
+            output::render_value(&output::to_value(summary)?, format)?
+        );
+        return Ok(());
+    }
+
+    let row = |label: &str, durations: [Duration; 4]| {
+        format!(
+            "{label:<6}  {}",
+            durations
+                .iter()
+                .map(|duration| format!("{:>12}", format!("{duration:.2?}")))
+                .join("  ")
+        )
+    };
+
+    println!(
+        "{:<6}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "day", "read", "parse", "solve", "total"
+    );
+    for (day, phases) in timings {
+        println!(
+            "{}",
+            row(
+                &day.to_string(),
+                [phases.read, phases.parse, phases.solve, phases.total()]
+            )
+        );
+    }
+    let sum =
+        |phase: fn(&Phases) -> Duration| timings.iter().map(|(_, phases)| phase(phases)).sum();
+    println!(
+        "{}",
+        row(
+            "total",
+            [
+                sum(|phases| phases.read),
+                sum(|phases| phases.parse),
+                sum(|phases| phases.solve),
+                total
+            ]
+        )
+    );
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use aoc_2024_core::day::Day;
+
+use crate::{implemented_solver, output, report, variants, Solver};
+
+struct Measurement {
+    algo: &'static str,
+    durations: Vec<Duration>,
+    peak_heap_bytes: Option<usize>,
+    peak_rss_bytes: Option<usize>,
+    answer: String,
+}
+
+impl Measurement {
+    fn take(
+        algo: &'static str,
+        solve: &Solver,
+        input: &str,
+        repetitions: usize,
+    ) -> anyhow::Result<Self> {
+        let ((answer, peak_heap_bytes), peak_rss_bytes) =
+            peak_rss_bytes(|| peak_heap_bytes(|| solve(input)));
+        let answer = answer?;
+
+        let durations = (0..repetitions)
+            .map(|_| -> anyhow::Result<Duration> {
+                let start = Instant::now();
+                solve(input)?;
+                Ok(start.elapsed())
+            })
+            .try_collect::<_, Vec<_>, _>()?
+            .into_iter()
+            .sorted()
+            .collect_vec();
+
+        Ok(Self {
+            algo,
+            durations,
+            peak_heap_bytes,
+            peak_rss_bytes,
+            answer: output::compact(&answer),
+        })
+    }
+
+    fn median(&self) -> Duration {
+        self.durations[self.durations.len() / 2]
+    }
+}
+
+#[cfg(feature = "dhat-heap")]
+fn peak_heap_bytes<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    let profiler = dhat::Profiler::builder().testing().build();
+    let result = f();
+    let stats = dhat::HeapStats::get();
+    drop(profiler);
+    (result, Some(stats.max_bytes))
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+fn peak_heap_bytes<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    (f(), None)
+}
+
+/// Peak resident set size while running `f`, on Linux only. Writing `5` to
+/// `clear_refs` resets the kernel's high-water mark, so earlier runs don't
+/// leak into the number.
+#[cfg(target_os = "linux")]
+fn peak_rss_bytes<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    let reset = std::fs::write("/proc/self/clear_refs", "5").is_ok();
+    let result = f();
+    (result, reset.then(read_vm_hwm).flatten())
+}
+
+#[cfg(target_os = "linux")]
+fn read_vm_hwm() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn peak_rss_bytes<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    (f(), None)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Runs every variant of `day` on `input` and prints their timings side by
+/// side, the first variant serves as the reference answer.
+pub fn compare_variants(day: Day, input: &str, repetitions: usize) -> anyhow::Result<()> {
+    let repetitions = repetitions.max(1);
+    let measurements = variants(day)?
+        .iter()
+        .map(|(algo, solve)| Measurement::take(algo, solve, input, repetitions))
+        .try_collect::<_, Vec<_>, _>()?;
+
+    let algo_width = measurements
+        .iter()
+        .map(|m| m.algo.len())
+        .chain(["algo".len()])
+        .max()
+        .unwrap_or_default();
+    let with_heap = measurements.iter().any(|m| m.peak_heap_bytes.is_some());
+    let with_rss = measurements.iter().any(|m| m.peak_rss_bytes.is_some());
+
+    print!(
+        "{:<algo_width$}  {:>12}  {:>12}  {:>12}",
+        "algo", "median", "min", "max"
+    );
+    if with_heap {
+        print!("  {:>14}", "peak heap");
+    }
+    if with_rss {
+        print!("  {:>14}", "peak RSS");
+    }
+    println!("  answer");
+
+    for m in &measurements {
+        print!(
+            "{:<algo_width$}  {:>12}  {:>12}  {:>12}",
+            m.algo,
+            format!("{:.2?}", m.median()),
+            format!("{:.2?}", m.durations.first().unwrap()),
+            format!("{:.2?}", m.durations.last().unwrap()),
+        );
+        if with_heap {
+            print!(
+                "  {:>14}",
+                m.peak_heap_bytes.map(format_bytes).unwrap_or_default()
+            );
+        }
+        if with_rss {
+            print!(
+                "  {:>14}",
+                m.peak_rss_bytes.map(format_bytes).unwrap_or_default()
+            );
+        }
+        if m.answer == measurements[0].answer {
+            println!("  {}", m.answer);
+        } else {
+            println!("  {} (differs from {})", m.answer, measurements[0].algo);
+        }
+    }
+
+    Ok(())
+}
+
+/// Median solve times of the default implementations, what `--save` writes
+/// and `--compare` reads.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Baseline {
+    repetitions: usize,
+    /// By day number.
+    median_ns: BTreeMap<u8, u64>,
+}
+
+impl Baseline {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("{} isn't a baseline", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+/// Times the default implementation of each day on its input and prints the
+/// medians, next to the ones in `compare` if given. Days without an input are
+/// skipped.
+pub fn compare_with_baseline(
+    inputs: &[(Day, PathBuf)],
+    repetitions: usize,
+    save: Option<&Path>,
+    compare: Option<&Path>,
+) -> anyhow::Result<()> {
+    let repetitions = repetitions.max(1);
+    let baseline = compare.map(Baseline::load).transpose()?;
+    if let Some(baseline) = baseline
+        .as_ref()
+        .filter(|baseline| baseline.repetitions != repetitions)
+    {
+        report::warning(format_args!(
+            "the baseline took the median of {} runs, this of {repetitions}",
+            baseline.repetitions
+        ));
+    }
+
+    println!(
+        "{:<6}  {:>12}  {:>12}  {:>8}",
+        "day", "baseline", "median", "change"
+    );
+    let mut current = Baseline {
+        repetitions,
+        median_ns: BTreeMap::new(),
+    };
+    for (day, input_path) in inputs {
+        if !input_path.exists() {
+            report::warning(format_args!(
+                "skipping {day}, {} does not exist",
+                input_path.display()
+            ));
+            continue;
+        }
+        let input = fs::read_to_string(input_path)
+            .with_context(|| format!("failed to read {}", input_path.display()))?;
+        let median =
+            Measurement::take("default", &implemented_solver(*day)?, &input, repetitions)?.median();
+        current
+            .median_ns
+            .insert(day.number(), median.as_nanos().try_into()?);
+
+        let before = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.median_ns.get(&day.number()))
+            .map(|&ns| Duration::from_nanos(ns));
+        println!(
+            "{:<6}  {:>12}  {:>12}  {:>8}",
+            day.to_string(),
+            before
+                .map(|before| format!("{before:.2?}"))
+                .unwrap_or_default(),
+            format!("{median:.2?}"),
+            before
+                .filter(|before| !before.is_zero())
+                .map(|before| format!(
+                    "{:+.1}%",
+                    (median.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+                ))
+                .unwrap_or_default(),
+        );
+    }
+
+    if let Some(path) = save {
+        current.save(path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use std::{
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::output::Format;
+
+/// Read from the working directory unless `--config` points elsewhere.
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Defaults for command line flags, so they don't have to be passed every
+/// time. Whatever is given on the command line, or through the environment,
+/// wins over the file.
+///
+/// ```toml
+/// input-dir = "inputs"
+/// session-file = "session.txt"
+/// output = "json"
+/// threads = 4
+///
+/// [hooks]
+/// on-finish = "say done"
+/// notify = true
+/// threshold = 5.0
+/// ```
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    /// Puzzle inputs as `day1.txt`, `day2.txt` and so on, relative to the
+    /// working directory.
+    pub input_dir: Option<PathBuf>,
+    pub session_file: Option<PathBuf>,
+    pub output: Option<Format>,
+    pub threads: Option<usize>,
+    pub hooks: Hooks,
+}
+
+/// What to do once a solve is done, see `--on-finish`, `--notify` and
+/// `--hook-threshold`.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Hooks {
+    pub on_finish: Option<String>,
+    pub notify: bool,
+    /// In seconds.
+    pub threshold: Option<f64>,
+}
+
+impl Config {
+    /// Reads `path`, or [`DEFAULT_PATH`] if that's `None`. Only an explicitly
+    /// given file has to exist.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let explicit = path.is_some();
+        let path = path.unwrap_or(Path::new(DEFAULT_PATH));
+        let text = match read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if !explicit && err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+        toml::from_str(&text).with_context(|| format!("{} isn't a valid config", path.display()))
+    }
+}
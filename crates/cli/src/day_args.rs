@@ -0,0 +1,105 @@
+use std::num::NonZeroUsize;
+
+use anyhow::anyhow;
+use aoc_2024_core::{day::Day, day_11, day_14, grid::GridSize};
+use clap::{FromArgMatches, Parser};
+use itertools::Itertools;
+
+use crate::{box_solver, Solver};
+
+/// Flags for single days, for what the puzzle says in its text rather than
+/// the input. The default implementation of the day solves with them, the
+/// counting one for day 11.
+#[derive(Debug, Clone, Default, PartialEq, Eq, clap::Args)]
+#[command(next_help_heading = "Day options", about = None, long_about = None)]
+pub struct DayArgs {
+    /// Day 11: how many times the stones blink for part 2
+    #[arg(long, value_name = "N")]
+    pub blinks: Option<usize>,
+
+    /// Day 14: how long the robots move for
+    #[arg(long)]
+    pub seconds: Option<usize>,
+
+    /// Day 14: width of the space, told from the robots' positions by default
+    #[arg(long, requires = "height")]
+    pub width: Option<NonZeroUsize>,
+
+    /// Day 14: height of the space
+    #[arg(long, requires = "width")]
+    pub height: Option<NonZeroUsize>,
+}
+
+/// [`DayArgs`] on their own, for reading them back from a recording.
+#[derive(Debug, Parser)]
+#[command(no_binary_name = true)]
+struct Standalone {
+    #[command(flatten)]
+    args: DayArgs,
+}
+
+impl DayArgs {
+    /// The flags that were given, as they would be on the command line.
+    pub fn to_args(&self) -> Vec<String> {
+        [
+            ("--blinks", self.blinks),
+            ("--seconds", self.seconds),
+            ("--width", self.width.map(NonZeroUsize::get)),
+            ("--height", self.height.map(NonZeroUsize::get)),
+        ]
+        .into_iter()
+        .filter_map(|(flag, value)| Some([flag.to_owned(), value?.to_string()]))
+        .flatten()
+        .collect()
+    }
+
+    pub fn from_args(args: &str) -> anyhow::Result<Self> {
+        let matches = <Standalone as clap::CommandFactory>::command()
+            .try_get_matches_from(args.split_whitespace())?;
+        Ok(Standalone::from_arg_matches(&matches)?.args)
+    }
+
+    /// The default solver of `day` with these flags, `None` if there are
+    /// none. Flags for another day are an error.
+    pub fn solver(&self, day: Day) -> anyhow::Result<Option<Solver>> {
+        let given = [
+            (11, "--blinks", self.blinks.is_some()),
+            (14, "--seconds", self.seconds.is_some()),
+            (14, "--width", self.width.is_some()),
+            (14, "--height", self.height.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, _, given)| *given)
+        .collect_vec();
+        if let Some((number, flag, _)) = given.iter().find(|(number, ..)| *number != day.number()) {
+            return Err(anyhow!("{flag} is only for {}", Day::new(*number)));
+        }
+
+        let solver = match day.number() {
+            _ if given.is_empty() => return Ok(None),
+            11 => {
+                let defaults = day_11::Params::default();
+                let params = day_11::Params {
+                    blinks: self.blinks.unwrap_or(defaults.blinks),
+                };
+                // The blinks can be many more than the puzzle's, too many for
+                // the default solver that recurses once per blink.
+                box_solver(move |input: &str| day_11::counting_solution(input, params))
+            }
+            14 => {
+                let defaults = day_14::Params::default();
+                let params = day_14::Params {
+                    space: self
+                        .width
+                        .zip(self.height)
+                        .map(|(width, height)| GridSize(height.get(), width.get()))
+                        .or(defaults.space),
+                    seconds: self.seconds.unwrap_or(defaults.seconds),
+                };
+                box_solver(move |input: &str| day_14::solution_with(input, params))
+            }
+            _ => unreachable!("every flag belongs to one of the days above"),
+        };
+        Ok(Some(solver))
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+use aoc_2024_core::day::Day;
+
+/// Something to do once a solve is done, so long runs can be left alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hook {
+    /// Run through `sh -c` with `AOC_DAY`, `AOC_ANSWER` (the answer as JSON)
+    /// and `AOC_DURATION_MS` set, and the same as JSON on stdin.
+    Command(String),
+    /// Pop up a desktop notification.
+    Notification,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Finished<'a> {
+    pub day: Day,
+    /// Single-line JSON, see [`crate::output::compact`].
+    pub answer: &'a str,
+    pub duration: Duration,
+}
+
+impl Finished<'_> {
+    fn to_json(self) -> String {
+        #[derive(serde::Serialize)]
+        struct Payload {
+            day: u8,
+            answer: serde_json::Value,
+            duration_ms: u128,
+        }
+
+        serde_json::to_string(&Payload {
+            day: self.day.number(),
+            answer: serde_json::from_str(self.answer)
+                .unwrap_or_else(|_| serde_json::Value::String(self.answer.to_owned())),
+            duration_ms: self.duration.as_millis(),
+        })
+        .expect("the payload serializes")
+    }
+
+    fn summary(self) -> String {
+        format!("{} finished in {:.1?}", self.day, self.duration)
+    }
+}
+
+/// `s` as an AppleScript string literal. Control characters are rejected
+/// rather than escaped, a notification has no use for them.
+fn applescript_string(s: &str) -> anyhow::Result<String> {
+    if let Some(ch) = s.chars().find(|ch| ch.is_control()) {
+        return Err(anyhow!("can't quote {ch:?} for AppleScript"));
+    }
+    Ok(format!(
+        "\"{}\"",
+        s.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+impl Hook {
+    pub fn run(&self, finished: Finished) -> anyhow::Result<()> {
+        match self {
+            Hook::Command(command) => run_command(command, finished)
+                .with_context(|| format!("post-run hook `{command}` failed")),
+            Hook::Notification => notify(finished).context("desktop notification failed"),
+        }
+    }
+}
+
+fn run_command(command: &str, finished: Finished) -> anyhow::Result<()> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .env("AOC_DAY", finished.day.number().to_string())
+        .env("AOC_ANSWER", finished.answer)
+        .env("AOC_DURATION_MS", finished.duration.as_millis().to_string())
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    // The hook may well not read its stdin, which is fine.
+    let _ = child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(finished.to_json().as_bytes());
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(anyhow!("exited with {status}"));
+    }
+    Ok(())
+}
+
+fn notify(finished: Finished) -> anyhow::Result<()> {
+    let summary = finished.summary();
+    let status = if cfg!(target_os = "macos") {
+        Command::new("osascript")
+            .args([
+                "-e",
+                &format!(
+                    "display notification {} with title \"aoc-2024\" subtitle {}",
+                    applescript_string(finished.answer)?,
+                    applescript_string(&summary)?
+                ),
+            ])
+            .status()?
+    } else {
+        Command::new("notify-send")
+            .args(["aoc-2024", &format!("{summary}\n{}", finished.answer)])
+            .status()?
+    };
+
+    if !status.success() {
+        return Err(anyhow!("exited with {status}"));
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! `visualize --image`: the frames as a PNG or an animated GIF instead of on
+//! the terminal. Needs the `render` feature.
+
+use std::{path::Path, time::Duration};
+
+use aoc_2024_core::viz;
+
+#[cfg(feature = "render")]
+pub fn save(
+    path: &Path,
+    delay: Duration,
+    visualize: impl FnOnce(&mut dyn viz::Sink) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    use std::{fs::File, io::BufWriter};
+
+    use anyhow::Context;
+    use aoc_2024_core::render::Images;
+
+    /// Pixels per side of a tile.
+    const SCALE: u32 = 4;
+
+    let file = BufWriter::new(
+        File::create(path).with_context(|| format!("failed to create {}", path.display()))?,
+    );
+    let mut images = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("png") => Images::png(file, SCALE),
+        Some("gif") => Images::gif(file, SCALE, delay)?,
+        _ => anyhow::bail!("{} should end in .png or .gif", path.display()),
+    };
+    visualize(&mut images)?;
+    Ok(images.finish()?)
+}
+
+#[cfg(not(feature = "render"))]
+pub fn save(
+    _path: &Path,
+    _delay: Duration,
+    _visualize: impl FnOnce(&mut dyn viz::Sink) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "`--image` needs the image encoders, build with `--features render`"
+    ))
+}
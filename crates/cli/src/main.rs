@@ -0,0 +1,777 @@
+use std::{
+    fs::{read_to_string, File},
+    net::{IpAddr, SocketAddr},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use anstream::{AutoStream, ColorChoice};
+use anyhow::{anyhow, Context};
+use aoc_2024_core::{
+    day::Day,
+    error::SolveError,
+    registry,
+    viz::{self, Pause},
+};
+use aoc_2024_net::{aoc_client, session};
+use clap::{parser::ValueSource, CommandFactory, FromArgMatches};
+use config::Config;
+use day_args::DayArgs;
+use hooks::{Finished, Hook};
+use itertools::Itertools;
+use output::Format;
+use replay::{Phases, Recording};
+use report::{CliError, Mismatch};
+
+mod all;
+mod bench;
+mod config;
+mod day_args;
+mod hooks;
+mod images;
+mod new_day;
+mod output;
+mod replay;
+mod report;
+mod selftest;
+mod serve;
+mod status;
+mod tui;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[derive(Debug, clap::Parser)]
+#[command(
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true,
+    after_help = report::EXIT_CODES
+)]
+struct Cli {
+    /// Day to solve, e.g. `5`, `day5` or `day_05`
+    #[arg(required = true)]
+    day: Option<Day>,
+
+    /// Fail if part 1 of the answer isn't this
+    #[arg(long = "expected-part1")]
+    expected_part_1: Option<String>,
+
+    /// Fail if part 2 of the answer isn't this
+    #[arg(long = "expected-part2")]
+    expected_part_2: Option<String>,
+
+    /// Also solve with the slow but straightforward implementation and fail
+    /// if the answers differ, needs the `reference` feature
+    #[arg(long)]
+    self_check: bool,
+
+    #[command(flatten)]
+    day_args: DayArgs,
+
+    #[arg(short = 'i', long, global = true, default_value = "puzzle_input.txt")]
+    puzzle_input_path: PathBuf,
+
+    /// Where puzzle inputs are kept as `day1.txt`, `day2.txt` and so on, used
+    /// when -i isn't given
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// File with defaults for these options, `aoc.toml` if it exists
+    #[arg(long, global = true, env = "AOC_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// adventofcode.com session token
+    #[arg(long, global = true)]
+    session: Option<String>,
+
+    /// File to read the session token from
+    #[arg(long, global = true)]
+    session_file: Option<PathBuf>,
+
+    /// Never touch the network, only use local files (also AOC_OFFLINE=1)
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Implementation to use for days with more than one
+    #[arg(long, global = true)]
+    algo: Option<String>,
+
+    /// Also print an analysis of the input, for days that have one
+    #[arg(long, global = true)]
+    explain: bool,
+
+    /// Shell command to run after solving, gets AOC_DAY, AOC_ANSWER and
+    /// AOC_DURATION_MS, and the same as JSON on stdin
+    #[arg(long, global = true)]
+    on_finish: Option<String>,
+
+    /// Send a desktop notification after solving
+    #[arg(long, global = true)]
+    notify: bool,
+
+    /// Only run the --on-finish and --notify hooks for solves taking at least
+    /// this many seconds
+    #[arg(long, global = true, default_value_t = 0.0)]
+    hook_threshold: f64,
+
+    /// Write what the solve depended on and how long it took to this file,
+    /// for `replay`
+    #[arg(long, global = true)]
+    record: Option<PathBuf>,
+
+    /// How to print answers, and errors with json and yaml
+    #[arg(
+        long,
+        visible_alias = "format",
+        global = true,
+        value_enum,
+        default_value_t
+    )]
+    output: Format,
+
+    /// When to use colors, `auto` leaves them off when NO_COLOR is set or
+    /// the output isn't a terminal
+    #[arg(long, global = true, value_enum, default_value_t)]
+    color: clap::ColorChoice,
+
+    /// Log what the solvers are doing to stderr, -v for debug and -vv for
+    /// trace events; RUST_LOG takes precedence when set
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Threads for the days that solve in parallel, all cores by default
+    #[arg(long, global = true, env = "AOC_THREADS")]
+    threads: Option<usize>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    /// Generate the skeleton of a new day module
+    NewDay {
+        day: Day,
+
+        /// The crate the day modules live in
+        #[arg(long, default_value = "crates/core")]
+        crate_root: PathBuf,
+    },
+    /// Show where the session token is picked up from
+    Session,
+    /// Time every implementation of a day against the puzzle input, or with
+    /// --save or --compare the default one of each day
+    Bench {
+        /// Every implemented day on the inputs in --input-dir (`inputs` by
+        /// default) if left out
+        day: Option<Day>,
+
+        #[arg(short = 'n', long, default_value_t = 10)]
+        repetitions: usize,
+
+        /// Write the median time of each day to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Show how the median times changed since --save wrote this file
+        #[arg(long)]
+        compare: Option<PathBuf>,
+    },
+    /// Solve every implemented day on the inputs in --input-dir (`inputs` by
+    /// default), then list how long each one took
+    All,
+    /// Check every day against its example and that the config and local
+    /// files are readable
+    Selftest,
+    /// Parse the puzzle input and check it for what the solution takes for
+    /// granted, without solving it
+    Validate { day: Day },
+    /// Compare the stars on adventofcode.com with the days implemented here
+    Status,
+    /// Pick days to solve on the inputs in --input-dir (`inputs` by default)
+    /// and look at their visualizations in a terminal UI, needs the `tui`
+    /// feature
+    Tui,
+    /// Solve puzzle inputs POSTed to /solve/{day} over HTTP, needs the
+    /// `serve` feature
+    Serve {
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+
+        /// Address to listen on, `0.0.0.0` to take requests from other hosts
+        #[arg(long, default_value = "127.0.0.1")]
+        host: IpAddr,
+    },
+    /// Solve again exactly as recorded with --record and compare the timings
+    Replay { recording: PathBuf },
+    /// Show a day's solution at work, step by step
+    Visualize {
+        day: Day,
+
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Wait for Enter after every frame
+        #[arg(long, conflicts_with = "delay_ms")]
+        step: bool,
+
+        /// Wait this long after every frame
+        #[arg(long)]
+        delay_ms: Option<u64>,
+
+        /// Only show every Nth frame, and the last one
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        every: u64,
+
+        /// Write the frames to this file instead of showing them
+        #[arg(long, conflicts_with_all = ["step", "delay_ms"])]
+        trace: Option<PathBuf>,
+
+        /// Save the last frame as a .png or every frame as a .gif, which shows
+        /// each for --delay-ms or 100ms
+        #[arg(long, conflicts_with_all = ["step", "trace"])]
+        image: Option<PathBuf>,
+    },
+    /// Print day 5's ordering rules as a Graphviz DOT graph, with the rules
+    /// an update breaks in red and those going round in a circle in bold
+    Dot {
+        day: Day,
+
+        /// Only the rules between the pages of this update, counting from 0
+        #[arg(long)]
+        update: Option<usize>,
+    },
+    /// Draw the puzzle input of a day as an SVG diagram, for the days that
+    /// have one
+    Diagram {
+        day: Day,
+
+        /// Where to write the SVG, stdout if left out
+        file: Option<PathBuf>,
+    },
+}
+
+type Solver = Box<dyn Fn(&str) -> anyhow::Result<serde_json::Value> + Send + Sync>;
+
+/// Prints the answer in `format` and hands it back, together with the input
+/// and how long reading and solving took.
+fn solve_puzzle_and_print<P: AsRef<Path>, F: FnOnce(&str) -> anyhow::Result<serde_json::Value>>(
+    day: Day,
+    input_path: P,
+    solve: F,
+    format: Format,
+) -> Result<(serde_json::Value, String, Phases), CliError> {
+    let input_path = input_path.as_ref();
+    let start = Instant::now();
+    let input = read_input(input_path)?;
+    let read = start.elapsed();
+    aoc_2024_core::phase::take_parsing_time();
+    let start = Instant::now();
+    let answer = solve(&input).map_err(|err| CliError::solving(input_path, &input, err))?;
+    let parse = aoc_2024_core::phase::take_parsing_time();
+    let solve = start.elapsed().saturating_sub(parse);
+    anstream::println!("{}", output::render(day, &answer, format)?);
+    Ok((answer, input, Phases { read, parse, solve }))
+}
+
+fn read_input(input_path: &Path) -> Result<String, CliError> {
+    read_to_string(input_path).map_err(|err| CliError::reading_input(input_path, err))
+}
+
+fn box_solver<
+    T: serde::Serialize + 'static,
+    F: 'static + Send + Sync + Fn(&str) -> Result<T, SolveError>,
+>(
+    solver: F,
+) -> Solver {
+    return Box::new(move |input: &str| output::to_value(solver(input)?));
+}
+
+/// The solver of `day`, an error if it isn't implemented.
+fn implemented_solver(day: Day) -> anyhow::Result<Solver> {
+    let solver = registry::solver(day).ok_or_else(|| anyhow!("{day} isn't implemented"))?;
+    Ok(Box::new(move |input: &str| Ok(solver.solve(input)?)))
+}
+
+/// The implementation `--self-check` compares the answer for `day` with.
+#[cfg(feature = "reference")]
+fn reference_solver(day: Day) -> anyhow::Result<Solver> {
+    use aoc_2024_core::{day_11, day_13, day_2, day_7, day_9};
+
+    match day.number() {
+        2 => Ok(box_solver(day_2::reference_solution)),
+        7 => Ok(box_solver(day_7::reference_solution)),
+        9 => Ok(box_solver(day_9::reference_solution)),
+        11 => Ok(box_solver(day_11::reference_solution)),
+        13 => Ok(box_solver(day_13::reference_solution)),
+        _ => Err(anyhow!("{day} has no reference implementation")),
+    }
+}
+
+#[cfg(not(feature = "reference"))]
+fn reference_solver(_day: Day) -> anyhow::Result<Solver> {
+    Err(anyhow!(
+        "--self-check needs the reference implementations, build with `--features reference`"
+    ))
+}
+
+type Explainer = fn(&str) -> Result<String, SolveError>;
+
+/// The input analysis `--explain` prints for `day`, if it has one.
+fn explainer(day: Day) -> Option<Explainer> {
+    match day.number() {
+        5 => Some(aoc_2024_core::day_5::explain),
+        16 => Some(aoc_2024_core::day_16::explain),
+        _ => None,
+    }
+}
+
+type Visualizer = fn(&str, &viz::Options, &mut dyn viz::Sink) -> Result<(), viz::Error>;
+
+/// What `visualize` shows for `day`, if anything.
+fn visualizer(day: Day) -> Option<Visualizer> {
+    match day.number() {
+        6 => Some(aoc_2024_core::day_6::visualize),
+        9 => Some(aoc_2024_core::day_9::visualize),
+        10 => Some(aoc_2024_core::day_10::visualize),
+        12 => Some(aoc_2024_core::day_12::visualize),
+        14 => Some(aoc_2024_core::day_14::visualize),
+        16 => Some(aoc_2024_core::day_16::visualize),
+        _ => None,
+    }
+}
+
+type Diagram = fn(&str) -> Result<String, SolveError>;
+
+/// What `diagram` draws for `day`, if anything.
+fn diagram(day: Day) -> Option<Diagram> {
+    match day.number() {
+        12 => Some(aoc_2024_core::day_12::diagram),
+        16 => Some(aoc_2024_core::day_16::diagram),
+        _ => None,
+    }
+}
+
+/// Every implementation of `day` that can be picked with `--algo`, the first
+/// one is the default.
+fn variants(day: Day) -> anyhow::Result<Vec<(&'static str, Solver)>> {
+    let mut variants = vec![("default", implemented_solver(day)?)];
+    match day.number() {
+        3 => variants.push((
+            "streaming",
+            box_solver(aoc_2024_core::day_3::streaming_solution),
+        )),
+        9 => variants.push((
+            "streaming",
+            box_solver(aoc_2024_core::day_9::streaming_solution),
+        )),
+        11 => variants.push((
+            "counting",
+            box_solver(|input: &str| {
+                aoc_2024_core::day_11::counting_solution(input, Default::default())
+            }),
+        )),
+        _ => {}
+    }
+    Ok(variants)
+}
+
+/// The variant `--algo` picks, together with its name.
+fn select_variant(day: Day, algo: Option<&str>) -> anyhow::Result<(&'static str, Solver)> {
+    let mut variants = variants(day)?;
+    let idx = match algo {
+        None => 0,
+        Some(algo) => variants
+            .iter()
+            .position(|(name, _)| *name == algo)
+            .ok_or_else(|| {
+                anyhow!(
+                    "unknown algorithm {algo} for {day}, available: {}",
+                    variants.iter().map(|(name, _)| name).join(", ")
+                )
+            })?,
+    };
+    Ok(variants.swap_remove(idx))
+}
+
+/// Runs `f` on a rayon pool of its own with `threads` threads, or on the
+/// global one if that's `None`.
+fn with_threads<T: Send>(
+    threads: Option<usize>,
+    f: impl FnOnce() -> T + Send,
+) -> anyhow::Result<T> {
+    match threads {
+        None => Ok(f()),
+        Some(threads) => Ok(rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()?
+            .install(f)),
+    }
+}
+
+fn init_logging(verbose: u8) {
+    let default_level = match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(default_level));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(AutoStream::choice(&std::io::stderr()) != ColorChoice::Never)
+        .init();
+}
+
+impl Cli {
+    /// The day the command works on, if any.
+    fn target_day(&self) -> Option<Day> {
+        match &self.command {
+            None => self.day,
+            Some(Command::Bench { day, .. }) => *day,
+            Some(
+                Command::Visualize { day, .. }
+                | Command::Diagram { day, .. }
+                | Command::Dot { day, .. }
+                | Command::Validate { day },
+            ) => Some(*day),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Parses the command line and fills in what it leaves out from the config
+/// file.
+fn parse_args() -> Result<Cli, CliError> {
+    let matches = Cli::command().try_get_matches().map_err(CliError::Usage)?;
+    let mut cli = Cli::from_arg_matches(&matches).map_err(CliError::Usage)?;
+    let config = Config::load(cli.config.as_deref())?;
+    let defaulted = |id: &str| {
+        matches!(
+            matches.value_source(id),
+            None | Some(ValueSource::DefaultValue)
+        )
+    };
+
+    cli.input_dir = cli.input_dir.or(config.input_dir);
+    cli.session_file = cli.session_file.or(config.session_file);
+    cli.threads = cli.threads.or(config.threads);
+    cli.on_finish = cli.on_finish.or(config.hooks.on_finish);
+    cli.notify |= config.hooks.notify;
+    if let Some(output) = config.output.filter(|_| defaulted("output")) {
+        cli.output = output;
+    }
+    if let Some(threshold) = config
+        .hooks
+        .threshold
+        .filter(|_| defaulted("hook_threshold"))
+    {
+        cli.hook_threshold = threshold;
+    }
+    if let (true, Some(input_dir), Some(day)) = (
+        defaulted("puzzle_input_path"),
+        &cli.input_dir,
+        cli.target_day(),
+    ) {
+        cli.puzzle_input_path = input_dir.join(format!("{day}.txt"));
+    }
+
+    Ok(cli)
+}
+
+fn main() -> ExitCode {
+    let cli = match parse_args() {
+        Ok(cli) => cli,
+        Err(err) => return report::report(err, Format::Plain),
+    };
+    let format = cli.output;
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => report::report(err, format),
+    }
+}
+
+fn run(mut cli: Cli) -> Result<(), CliError> {
+    output::set_color_choice(cli.color);
+    init_logging(cli.verbose);
+    if cli.offline {
+        // Picked up by every `AocClient` created from here on.
+        std::env::set_var(aoc_client::OFFLINE_ENV_VAR, "1");
+    }
+
+    let Some(command) = cli.command.take() else {
+        let day = cli.day.expect("clap requires a day without a command");
+        return solve(&cli, day);
+    };
+    match command {
+        Command::NewDay { day, crate_root } => Ok(new_day::scaffold(&crate_root, day)?),
+        Command::Session => {
+            let token = session::resolve(cli.session.as_deref(), cli.session_file.as_deref())?;
+            println!("using session token from {}", token.source);
+            Ok(())
+        }
+        Command::Bench {
+            day: Some(day),
+            repetitions,
+            save: None,
+            compare: None,
+        } => {
+            let input = read_input(&cli.puzzle_input_path)?;
+            with_threads(cli.threads, || {
+                bench::compare_variants(day, &input, repetitions)
+            })??;
+            Ok(())
+        }
+        Command::Bench {
+            day,
+            repetitions,
+            save,
+            compare,
+        } => {
+            let inputs = match day {
+                Some(day) => vec![(day, cli.puzzle_input_path.clone())],
+                None => {
+                    let input_dir = cli.input_dir.as_deref().unwrap_or(Path::new("inputs"));
+                    aoc_2024_core::IMPLEMENTED_DAYS
+                        .iter()
+                        .map(|&day| (day, input_dir.join(format!("{day}.txt"))))
+                        .collect()
+                }
+            };
+            with_threads(cli.threads, || {
+                bench::compare_with_baseline(
+                    &inputs,
+                    repetitions,
+                    save.as_deref(),
+                    compare.as_deref(),
+                )
+            })??;
+            Ok(())
+        }
+        Command::Status => {
+            let token = session::resolve(cli.session.as_deref(), cli.session_file.as_deref())?;
+            Ok(status::print(token)?)
+        }
+        Command::Serve { port, host } => Ok(serve::run(SocketAddr::new(host, port))?),
+        Command::Replay { recording } => replay(&recording),
+        Command::Tui => {
+            let input_dir = cli.input_dir.as_deref().unwrap_or(Path::new("inputs"));
+            Ok(with_threads(cli.threads, || tui::run(input_dir))??)
+        }
+        Command::All => {
+            let input_dir = cli.input_dir.as_deref().unwrap_or(Path::new("inputs"));
+            with_threads(cli.threads, || all::run(input_dir, cli.output))?
+        }
+        Command::Visualize {
+            day,
+            part,
+            step,
+            delay_ms,
+            every,
+            trace,
+            image,
+        } => {
+            let visualize = visualizer(day).ok_or_else(|| anyhow!("{day} has no visualization"))?;
+            let pause = match (step, delay_ms) {
+                (true, _) => Pause::Step,
+                (false, Some(delay_ms)) => Pause::Delay(Duration::from_millis(delay_ms)),
+                (false, None) => Pause::None,
+            };
+            let input = read_input(&cli.puzzle_input_path)?;
+            let options = viz::Options {
+                part,
+                color: trace.is_none()
+                    && image.is_none()
+                    && AutoStream::choice(&std::io::stdout()) != ColorChoice::Never,
+            };
+            let run = |sink: &mut dyn viz::Sink| {
+                let mut sink = viz::Every::new(every as usize, sink);
+                visualize(&input, &options, &mut sink)?;
+                sink.finish()?;
+                Ok(())
+            };
+            match (&trace, &image) {
+                (Some(path), _) => run(&mut viz::Trace::new(
+                    File::create(path)
+                        .with_context(|| format!("failed to create {}", path.display()))?,
+                )),
+                (None, Some(path)) => {
+                    images::save(path, Duration::from_millis(delay_ms.unwrap_or(100)), run)
+                }
+                (None, None) => run(&mut viz::Terminal::new(pause)),
+            }
+            .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err))
+        }
+        Command::Diagram { day, file } => {
+            let diagram = diagram(day).ok_or_else(|| anyhow!("{day} has no diagram"))?;
+            let input = read_input(&cli.puzzle_input_path)?;
+            let svg = diagram(&input)
+                .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err.into()))?;
+            match file {
+                Some(file) => std::fs::write(&file, svg)
+                    .with_context(|| format!("failed to write {}", file.display()))?,
+                None => print!("{svg}"),
+            }
+            Ok(())
+        }
+        Command::Dot { day, update } => {
+            if day.number() != 5 {
+                return Err(anyhow!("{day} has no graph").into());
+            }
+            let input = read_input(&cli.puzzle_input_path)?;
+            let dot = aoc_2024_core::day_5::dot(&input, update)
+                .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err.into()))?;
+            print!("{dot}");
+            Ok(())
+        }
+        Command::Validate { day } => validate(&cli, day),
+        Command::Selftest => {
+            let passed = selftest::run(
+                &cli.puzzle_input_path,
+                cli.input_dir.as_deref().unwrap_or(Path::new("inputs")),
+                cli.config.as_deref(),
+                cli.session.as_deref(),
+                cli.session_file.as_deref(),
+            );
+            if passed {
+                Ok(())
+            } else {
+                Err(anyhow!("self-test failed").into())
+            }
+        }
+    }
+}
+
+fn solve(cli: &Cli, day: Day) -> Result<(), CliError> {
+    let (algo, solver) = match cli.day_args.solver(day)? {
+        None => select_variant(day, cli.algo.as_deref())?,
+        Some(_) if cli.algo.is_some() => {
+            return Err(anyhow!("day options only go with the default algorithm").into())
+        }
+        Some(solver) => ("default", solver),
+    };
+    let (answer, input, phases) = with_threads(cli.threads, || {
+        solve_puzzle_and_print(day, &cli.puzzle_input_path, solver, cli.output)
+    })??;
+    let duration = phases.parse + phases.solve;
+    let compact_answer = output::compact(&answer);
+
+    if let Some(path) = &cli.record {
+        Recording {
+            day,
+            input_path: cli.puzzle_input_path.clone(),
+            input_hash: replay::hash_input(&input),
+            threads: cli.threads.unwrap_or_else(rayon::current_num_threads),
+            algo: algo.to_owned(),
+            day_args: cli.day_args.clone(),
+            answer: compact_answer.clone(),
+            phases,
+        }
+        .save(path)?;
+    }
+
+    let hooks = cli
+        .on_finish
+        .iter()
+        .map(|command| Hook::Command(command.clone()))
+        .chain(cli.notify.then_some(Hook::Notification));
+    if duration.as_secs_f64() >= cli.hook_threshold {
+        let finished = Finished {
+            day,
+            answer: &compact_answer,
+            duration,
+        };
+        for hook in hooks {
+            // The answer is already out, a broken hook shouldn't fail the run.
+            if let Err(err) = hook.run(finished) {
+                report::warning(format_args!("{err:#}"));
+            }
+        }
+    }
+
+    if cli.explain {
+        let Some(explain) = explainer(day) else {
+            return Err(anyhow!("{day} has nothing to explain").into());
+        };
+        let explanation = explain(&input)
+            .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err.into()))?;
+        println!("{explanation}");
+    }
+
+    let expectations = [
+        ("part_1", &cli.expected_part_1),
+        ("part_2", &cli.expected_part_2),
+    ];
+    let mut mismatches = expectations
+        .into_iter()
+        .filter_map(|(part, expected)| {
+            let expected = expected.as_ref()?;
+            let actual = output::field(&answer, part);
+            (actual.as_ref() != Some(expected)).then(|| Mismatch {
+                part,
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect_vec();
+
+    if cli.self_check {
+        let reference = reference_solver(day)?(&input)
+            .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err))?;
+        mismatches.extend(["part_1", "part_2"].into_iter().filter_map(|part| {
+            let expected = output::field(&reference, part)?;
+            let actual = output::field(&answer, part);
+            (actual.as_ref() != Some(&expected)).then_some(Mismatch {
+                part,
+                expected,
+                actual,
+            })
+        }));
+    }
+    if !mismatches.is_empty() {
+        return Err(CliError::WrongAnswer(mismatches));
+    }
+
+    Ok(())
+}
+
+/// Prints what the checks of `day` make of the puzzle input, and fails if
+/// they found problems with it.
+fn validate(cli: &Cli, day: Day) -> Result<(), CliError> {
+    let solver = registry::solver(day).ok_or_else(|| anyhow!("{day} isn't implemented"))?;
+    let input = read_input(&cli.puzzle_input_path)?;
+    let report = solver
+        .validate(&input)
+        .map_err(|err| CliError::solving(&cli.puzzle_input_path, &input, err.into()))?;
+    match cli.output {
+        Format::Plain => println!("{report}"),
+        format => println!(
+            "{}",
+            output::render_value(&output::to_value(&report)?, format)?
+        ),
+    }
+
+    if report.is_valid() {
+        Ok(())
+    } else {
+        Err(CliError::Invalid(report.problems.len()))
+    }
+}
+
+fn replay(path: &Path) -> Result<(), CliError> {
+    let recording = Recording::load(path)?;
+
+    let solver = match recording.day_args.solver(recording.day)? {
+        None => select_variant(recording.day, Some(&recording.algo))?.1,
+        Some(solver) => solver,
+    };
+    let input = read_input(&recording.input_path)?;
+    recording.check_input(&input)?;
+    let (answer, _, phases) = with_threads(Some(recording.threads), || {
+        solve_puzzle_and_print(recording.day, &recording.input_path, solver, Format::Plain)
+    })??;
+    Ok(recording.compare(&output::compact(&answer), phases)?)
+}
@@ -0,0 +1,66 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::Path,
+};
+
+use anyhow::anyhow;
+use aoc_2024_core::day::Day;
+
+const TEMPLATE: &str = include_str!("./templates/day.rs.in");
+
+pub fn scaffold(crate_root: &Path, day: Day) -> anyhow::Result<()> {
+    let number = day.number();
+    let src_dir = crate_root.join("src");
+    let module_path = src_dir.join(format!("day_{number}.rs"));
+    let examples_dir = src_dir.join("examples").join(day.to_string());
+
+    if module_path.exists() {
+        return Err(anyhow!("{} already exists", module_path.display()));
+    }
+
+    create_dir_all(&examples_dir)?;
+    let example_path = examples_dir.join("example.txt");
+    if !example_path.exists() {
+        write(&example_path, "")?;
+        println!("created {}", example_path.display());
+    }
+
+    write(&module_path, render(day))?;
+    println!("created {}", module_path.display());
+
+    println!();
+    println!("the module is picked up on the next build, run it with `cargo run -- {day}`");
+
+    Ok(())
+}
+
+fn render(day: Day) -> String {
+    TEMPLATE.replace("{{DAY}}", &day.number().to_string())
+}
+
+/// A new day has to fit the registry's list of days, and nothing but the next
+/// build after `new-day` would tell otherwise.
+#[test]
+fn template_fits_registry() {
+    let registry = include_str!("../../core/src/registry.rs");
+    let module = render(Day::new(25));
+    assert!(!module.contains("{{"));
+
+    for function in ["solution", "validate"] {
+        let field = format!("{function}: fn(&str) -> ");
+        let returns = registry
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(&field))
+            .unwrap_or_else(|| panic!("the registry has no `{field}`"))
+            .trim_end_matches(',')
+            .replace("<A,", "<Answer,");
+        let signature = module
+            .lines()
+            .find(|line| line.starts_with(&format!("pub fn {function}")))
+            .unwrap_or_else(|| panic!("the template has no `{function}`"));
+        assert!(
+            signature.ends_with(&format!("-> {returns} {{")),
+            "`{signature}` doesn't return `{returns}`"
+        );
+    }
+}
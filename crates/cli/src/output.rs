@@ -0,0 +1,120 @@
+use anstyle::{AnsiColor, Style};
+use aoc_2024_core::day::Day;
+use serde_json::Value;
+
+const DAY: Style = AnsiColor::Cyan.on_default().bold();
+const PART: Style = Style::new().bold();
+const VALUE: Style = AnsiColor::Green.on_default().bold();
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// A `Day N` heading and one `Part N: value` line per part, colored on
+    /// terminals
+    #[default]
+    Plain,
+    Json,
+    Yaml,
+}
+
+/// Applies `--color` to everything printed through `anstream`, which looks at
+/// NO_COLOR and whether it writes to a terminal on `auto`.
+pub fn set_color_choice(choice: clap::ColorChoice) {
+    match choice {
+        clap::ColorChoice::Auto => anstream::ColorChoice::Auto,
+        clap::ColorChoice::Always => anstream::ColorChoice::Always,
+        clap::ColorChoice::Never => anstream::ColorChoice::Never,
+    }
+    .write_global();
+}
+
+/// Turns a day's answer into the form every output format is rendered from.
+pub fn to_value(answer: impl serde::Serialize) -> anyhow::Result<Value> {
+    Ok(serde_json::to_value(answer)?)
+}
+
+/// The answer on a single line, for hooks, recordings and comparing answers.
+pub fn compact(answer: &Value) -> String {
+    answer.to_string()
+}
+
+/// `answer` to `day` in `format`. Plain text comes with ANSI styles, print
+/// it through `anstream` so they are dropped where colors are off.
+pub fn render(day: Day, answer: &Value, format: Format) -> anyhow::Result<String> {
+    Ok(match format {
+        Format::Plain => {
+            let heading = format!("{DAY}Day {}{DAY:#}", day.number());
+            let parts = match answer {
+                Value::Object(fields) => fields
+                    .iter()
+                    .map(|(name, value)| {
+                        format!(
+                            "{PART}{}:{PART:#} {VALUE}{}{VALUE:#}",
+                            label(name),
+                            plain(value)
+                        )
+                    })
+                    .collect(),
+                value => vec![format!("{VALUE}{}{VALUE:#}", plain(value))],
+            };
+            [heading]
+                .into_iter()
+                .chain(parts)
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        format => render_value(answer, format)?,
+    })
+}
+
+/// Any value in `format`, plain text is a bare value without styles.
+pub fn render_value(value: &Value, format: Format) -> anyhow::Result<String> {
+    Ok(match format {
+        Format::Plain => plain(value),
+        Format::Json => serde_json::to_string_pretty(value)?,
+        Format::Yaml => serde_yaml::to_string(value)?.trim_end().to_owned(),
+    })
+}
+
+/// `part_1` reads as `Part 1`, other fields keep their name.
+fn label(name: &str) -> String {
+    match name.strip_prefix("part_") {
+        Some(number) => format!("Part {number}"),
+        None => name.to_owned(),
+    }
+}
+
+/// Strings without their quotes, anything else as JSON.
+fn plain(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// The part `name` of an answer as plain text.
+pub fn field(answer: &Value, name: &str) -> Option<String> {
+    answer.get(name).map(plain)
+}
+
+/// Every day's answers to its examples as they're printed in each format,
+/// as snapshots in `src/snapshots`. Review changes with `cargo insta review`.
+#[test]
+fn examples() {
+    use aoc_2024_core::{examples, registry::SOLVERS};
+
+    for solver in SOLVERS {
+        let day = solver.day();
+        for (variant, input) in examples::all(day) {
+            let answer = solver.solve(input).unwrap();
+            let rendered = [Format::Plain, Format::Json, Format::Yaml]
+                .map(|format| {
+                    let rendered = render(day, &answer, format).unwrap();
+                    anstream::adapter::strip_str(&rendered).to_string()
+                })
+                .join("\n\n");
+            insta::assert_snapshot!(format!("{day}_{variant}"), rendered);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt::Write as _,
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+use aoc_2024_core::day::Day;
+
+use crate::day_args::DayArgs;
+
+/// Everything a solve depended on, so it can be run again the same way on
+/// another machine. Solves don't use any randomness, so there are no seeds
+/// to keep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recording {
+    pub day: Day,
+    pub input_path: PathBuf,
+    /// FNV-1a of the input, to notice when the file changed since.
+    pub input_hash: u64,
+    /// Size of rayon's thread pool.
+    pub threads: usize,
+    pub algo: String,
+    pub day_args: DayArgs,
+    pub answer: String,
+    pub phases: Phases,
+}
+
+/// How long each step of a solve took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phases {
+    pub read: Duration,
+    pub parse: Duration,
+    /// Without parsing.
+    pub solve: Duration,
+}
+
+impl Phases {
+    pub fn iter(self) -> impl Iterator<Item = (&'static str, Duration)> {
+        [
+            ("read", self.read),
+            ("parse", self.parse),
+            ("solve", self.solve),
+        ]
+        .into_iter()
+    }
+
+    pub fn total(self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+}
+
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Recording {
+    /// One `key: value` line per field.
+    fn to_text(&self) -> String {
+        let mut text = String::new();
+        writeln!(text, "day: {}", self.day).unwrap();
+        writeln!(text, "input: {}", self.input_path.display()).unwrap();
+        writeln!(text, "input-hash: {:016x}", self.input_hash).unwrap();
+        writeln!(text, "threads: {}", self.threads).unwrap();
+        writeln!(text, "algo: {}", self.algo).unwrap();
+        writeln!(text, "args: {}", self.day_args.to_args().join(" ")).unwrap();
+        writeln!(text, "answer: {}", self.answer).unwrap();
+        for (phase, duration) in self.phases.iter() {
+            writeln!(text, "{phase}-ns: {}", duration.as_nanos()).unwrap();
+        }
+        text
+    }
+
+    fn from_text(text: &str) -> anyhow::Result<Self> {
+        let field = |key: &str| {
+            text.lines()
+                .find_map(|line| line.strip_prefix(key)?.strip_prefix(": "))
+                .ok_or_else(|| anyhow!("missing `{key}`"))
+        };
+        let duration = |key: &str| -> anyhow::Result<Duration> {
+            Ok(Duration::from_nanos(
+                field(key)?
+                    .parse()
+                    .with_context(|| format!("bad `{key}`"))?,
+            ))
+        };
+
+        Ok(Self {
+            day: field("day")?.parse()?,
+            input_path: field("input")?.into(),
+            input_hash: u64::from_str_radix(field("input-hash")?, 16)
+                .context("bad `input-hash`")?,
+            threads: field("threads")?.parse().context("bad `threads`")?,
+            algo: field("algo")?.to_owned(),
+            // Recordings from before there were day options have no `args`.
+            day_args: match field("args") {
+                Ok(args) => DayArgs::from_args(args).context("bad `args`")?,
+                Err(_) => DayArgs::default(),
+            },
+            answer: field("answer")?.to_owned(),
+            phases: Phases {
+                read: duration("read-ns")?,
+                parse: duration("parse-ns")?,
+                solve: duration("solve-ns")?,
+            },
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        write(path, self.to_text()).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text =
+            read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_text(&text).with_context(|| format!("{} isn't a recording", path.display()))
+    }
+
+    /// Checks that `input` is what was recorded.
+    pub fn check_input(&self, input: &str) -> anyhow::Result<()> {
+        if hash_input(input) != self.input_hash {
+            return Err(anyhow!(
+                "{} changed since it was recorded",
+                self.input_path.display()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Compares a replay with the recording, an answer that differs is an
+    /// error.
+    pub fn compare(&self, answer: &str, phases: Phases) -> anyhow::Result<()> {
+        for ((phase, recorded), (_, replayed)) in self.phases.iter().zip(phases.iter()) {
+            println!("{phase:<5}  recorded {recorded:>12.2?}  replayed {replayed:>12.2?}");
+        }
+        if answer != self.answer {
+            return Err(anyhow!("the recorded answer was {}", self.answer));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fmt::Display,
+    io,
+    path::{absolute, Path, PathBuf},
+    process::ExitCode,
+};
+
+use anstream::{eprintln, println};
+use anstyle::{AnsiColor, Style};
+use aoc_2024_core::{
+    day_5::DotError,
+    error::{ParseError, SolveError},
+    viz,
+};
+use aoc_2024_net::{aoc_client::Offline, session::MissingSessionToken};
+use itertools::Itertools;
+
+use crate::output::{self, Format};
+
+const ERROR: Style = AnsiColor::Red.on_default().bold();
+const WARNING: Style = AnsiColor::Yellow.on_default().bold();
+const HINT: Style = AnsiColor::Cyan.on_default().bold();
+const GUTTER: Style = AnsiColor::Blue.on_default().bold();
+
+#[derive(Debug)]
+pub enum CliError {
+    Usage(clap::Error),
+    InputNotFound(PathBuf),
+    ReadInput(PathBuf, io::Error),
+    Parse {
+        path: PathBuf,
+        input: String,
+        error: ParseError,
+    },
+    /// The solver gave up on an input it could parse.
+    Unsolvable {
+        path: PathBuf,
+        error: anyhow::Error,
+    },
+    /// Parts of the answer that aren't what `--expected-part1` and
+    /// `--expected-part2` said.
+    WrongAnswer(Vec<Mismatch>),
+    /// How many problems `validate` found, the report listing them is
+    /// already out.
+    Invalid(usize),
+    Other(anyhow::Error),
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct Mismatch {
+    pub part: &'static str,
+    pub expected: String,
+    /// `None` if the answer has no such part.
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.part,
+            self.expected,
+            self.actual.as_deref().unwrap_or("nothing")
+        )
+    }
+}
+
+impl From<anyhow::Error> for CliError {
+    fn from(err: anyhow::Error) -> Self {
+        CliError::Other(err)
+    }
+}
+
+impl CliError {
+    pub fn reading_input(path: &Path, err: io::Error) -> Self {
+        let path = absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        match err.kind() {
+            io::ErrorKind::NotFound => CliError::InputNotFound(path),
+            _ => CliError::ReadInput(path, err),
+        }
+    }
+
+    pub fn solving(path: &Path, input: &str, err: anyhow::Error) -> Self {
+        // Visualizations and day 5's graph wrap the errors of solving in
+        // errors of their own.
+        let err = match err.downcast::<viz::Error>() {
+            Ok(viz::Error::Solve(error)) => error.into(),
+            Ok(error) => error.into(),
+            Err(err) => match err.downcast::<DotError>() {
+                Ok(DotError::Parse(error)) => error.into(),
+                Ok(error) => error.into(),
+                Err(err) => err,
+            },
+        };
+        let parse_error = match err.downcast::<SolveError>() {
+            Ok(SolveError::Parse(error)) => Ok(error),
+            Ok(error) => Err(error.into()),
+            Err(err) => err.downcast::<ParseError>(),
+        };
+        match parse_error {
+            Ok(error) => CliError::Parse {
+                path: path.to_path_buf(),
+                input: input.to_owned(),
+                error,
+            },
+            Err(error) => CliError::Unsolvable {
+                path: path.to_path_buf(),
+                error,
+            },
+        }
+    }
+
+    /// What went wrong in a word, for scripts.
+    fn kind(&self) -> &'static str {
+        match self {
+            CliError::Usage(_) => "usage",
+            CliError::InputNotFound(_) => "input-not-found",
+            CliError::ReadInput(..) => "read-input",
+            CliError::Parse { .. } => "parse",
+            CliError::Unsolvable { .. } => "unsolvable",
+            CliError::WrongAnswer(_) => "wrong-answer",
+            CliError::Invalid(_) => "invalid-input",
+            CliError::Other(_) => "other",
+        }
+    }
+
+    /// Listed in `--help`, keep [`EXIT_CODES`] in sync.
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(err) => u8::try_from(err.exit_code()).unwrap_or(2),
+            CliError::InputNotFound(_) => 3,
+            CliError::ReadInput(..) => 4,
+            CliError::Parse { .. } => 5,
+            CliError::Unsolvable { .. } => 6,
+            CliError::WrongAnswer(_) => 7,
+            CliError::Invalid(_) => 8,
+            CliError::Other(_) => 1,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            CliError::Usage(err) => err.render().to_string().trim_end().to_owned(),
+            CliError::InputNotFound(path) => {
+                format!("puzzle input {} does not exist", path.display())
+            }
+            CliError::ReadInput(path, err) => format!("failed to read {}: {}", path.display(), err),
+            CliError::Parse { error, .. } => error.to_string(),
+            CliError::Unsolvable { path, error } => {
+                format!("no solution for {}: {error}", path.display())
+            }
+            CliError::WrongAnswer(mismatches) => mismatches.iter().join("; "),
+            CliError::Invalid(1) => "the puzzle input has a problem".to_owned(),
+            CliError::Invalid(problems) => format!("the puzzle input has {problems} problems"),
+            CliError::Other(err) => err.to_string(),
+        }
+    }
+
+    fn envelope(&self) -> Envelope<'_> {
+        let (path, line, column) = match self {
+            CliError::InputNotFound(path) | CliError::ReadInput(path, _) => {
+                (Some(path.clone()), None, None)
+            }
+            CliError::Parse { path, error, .. } => {
+                (Some(path.clone()), Some(error.line), Some(error.column))
+            }
+            CliError::Unsolvable { path, .. } => (Some(path.clone()), None, None),
+            CliError::Usage(_)
+            | CliError::WrongAnswer(_)
+            | CliError::Invalid(_)
+            | CliError::Other(_) => (None, None, None),
+        };
+        let causes = match self {
+            CliError::Unsolvable { error: err, .. } | CliError::Other(err) => {
+                err.chain().skip(1).map(ToString::to_string).collect()
+            }
+            _ => Vec::new(),
+        };
+        Envelope {
+            error: ErrorReport {
+                kind: self.kind(),
+                exit_code: self.exit_code(),
+                message: self.message(),
+                path,
+                line,
+                column,
+                causes,
+                mismatches: match self {
+                    CliError::WrongAnswer(mismatches) => mismatches,
+                    _ => &[],
+                },
+            },
+        }
+    }
+}
+
+pub const EXIT_CODES: &str = "\
+Exit codes:
+  0  success
+  1  any other failure
+  2  invalid arguments
+  3  the puzzle input does not exist
+  4  the puzzle input can't be read
+  5  the puzzle input doesn't parse
+  6  the puzzle has no solution for the input
+  7  the answer isn't the one --expected-part1/--expected-part2 asked for
+  8  `validate` found problems with the puzzle input";
+
+/// What `--output json` and `--output yaml` print on failure, on stdout like
+/// the answer would have been.
+#[derive(Debug, serde::Serialize)]
+struct Envelope<'a> {
+    error: ErrorReport<'a>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ErrorReport<'a> {
+    kind: &'static str,
+    exit_code: u8,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    causes: Vec<String>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    mismatches: &'a [Mismatch],
+}
+
+fn error(message: impl Display) {
+    eprintln!("{ERROR}error:{ERROR:#} {message}");
+}
+
+pub fn warning(message: impl Display) {
+    eprintln!("{WARNING}warning:{WARNING:#} {message}");
+}
+
+fn hint(message: impl Display) {
+    eprintln!("{HINT}hint:{HINT:#} {message}");
+}
+
+/// Prints `err` for a person, or as an [`Envelope`] when answers are printed
+/// for scripts, and picks the exit code that goes with it.
+pub fn report(err: CliError, format: Format) -> ExitCode {
+    let exit_code = ExitCode::from(err.exit_code());
+    // The report `validate` printed says it all.
+    if let (CliError::Invalid(_), Format::Json | Format::Yaml) = (&err, format) {
+        return exit_code;
+    }
+    if format != Format::Plain {
+        let envelope = output::to_value(err.envelope())
+            .and_then(|envelope| output::render_value(&envelope, format));
+        match envelope {
+            Ok(envelope) => {
+                println!("{envelope}");
+                return exit_code;
+            }
+            Err(render_err) => error(format_args!("{render_err:#}")),
+        }
+    }
+
+    let message = err.message();
+    match err {
+        CliError::Usage(err) => {
+            let _ = err.print();
+        }
+        CliError::InputNotFound(_) => {
+            error(message);
+            hint("pass the path of your puzzle input with -i/--puzzle-input-path");
+        }
+        CliError::ReadInput(..) | CliError::WrongAnswer(_) | CliError::Invalid(_) => error(message),
+        CliError::Parse { path, input, error } => {
+            let line_number = error.line.to_string();
+            let padding = " ".repeat(line_number.len());
+
+            self::error(&error);
+            eprintln!(
+                "{padding}{GUTTER}-->{GUTTER:#} {}:{}:{}",
+                path.display(),
+                error.line,
+                error.column
+            );
+            eprintln!("{padding} {GUTTER}|{GUTTER:#}");
+            eprintln!(
+                "{GUTTER}{line_number} |{GUTTER:#} {}",
+                error.line_text(&input)
+            );
+            eprintln!(
+                "{padding} {GUTTER}|{GUTTER:#} {}{ERROR}^{ERROR:#}",
+                " ".repeat(error.column - 1)
+            );
+        }
+        CliError::Unsolvable { error: err, .. } => {
+            error(message);
+            for cause in err.chain().skip(1) {
+                eprintln!("  caused by: {cause}");
+            }
+        }
+        CliError::Other(err) => {
+            if let Some(missing) = err.downcast_ref::<MissingSessionToken>() {
+                error(missing);
+                hint(
+                    "pass --session or set AOC_SESSION to the `session` cookie of adventofcode.com",
+                );
+                if let Some(token_file) = &missing.token_file {
+                    hint(format_args!(
+                        "or save it once with `echo <token> > {}`",
+                        token_file.display()
+                    ));
+                }
+            } else if let Some(offline) = err.downcast_ref::<Offline>() {
+                error(offline);
+                hint("drop --offline and unset AOC_OFFLINE to allow network access");
+            } else {
+                error(&err);
+                for cause in err.chain().skip(1) {
+                    eprintln!("  caused by: {cause}");
+                }
+            }
+        }
+    }
+
+    exit_code
+}
@@ -0,0 +1,167 @@
+use std::{fs::File, path::Path};
+
+use anstream::println;
+use anstyle::{AnsiColor, Style};
+use aoc_2024_core::{day::Day, examples};
+use aoc_2024_net::session;
+
+use crate::{
+    config::{self, Config},
+    implemented_solver,
+    output::{self, Format},
+};
+
+const OK: Style = AnsiColor::Green.on_default().bold();
+const FAILED: Style = AnsiColor::Red.on_default().bold();
+const SKIPPED: Style = AnsiColor::Yellow.on_default().bold();
+
+/// An example from the puzzle text together with the answers the puzzle
+/// states for it, `None` where it doesn't state one.
+struct Example {
+    day: Day,
+    variant: &'static str,
+    part_1: Option<&'static str>,
+    part_2: Option<&'static str>,
+}
+
+macro_rules! example {
+    ($day:literal, $variant:literal, $part_1:expr, $part_2:expr) => {
+        Example {
+            day: Day::new($day),
+            variant: $variant,
+            part_1: $part_1,
+            part_2: $part_2,
+        }
+    };
+}
+
+const EXAMPLES: &[Example] = &[
+    example!(1, "example", Some("11"), Some("31")),
+    example!(2, "example", Some("2"), Some("4")),
+    example!(3, "example.2", Some("161"), Some("48")),
+    example!(4, "example", Some("18"), Some("9")),
+    example!(5, "example", Some("143"), Some("123")),
+    example!(6, "example", Some("41"), Some("6")),
+    example!(7, "example", Some("3749"), Some("11387")),
+    example!(8, "example", Some("14"), Some("34")),
+    example!(9, "example", Some("1928"), Some("2858")),
+    example!(10, "example", Some("36"), Some("81")),
+    example!(11, "example", Some("55312"), None),
+    example!(12, "example.3", Some("1930"), Some("1206")),
+    example!(13, "example", Some("480"), Some("875318608908")),
+    example!(14, "example", Some("12"), None),
+    example!(16, "example.1", Some("7036"), None),
+];
+
+/// Whether the self-test checks `day` against answers from the puzzle text.
+pub fn has_expected_answers(day: Day) -> bool {
+    EXAMPLES
+        .iter()
+        .any(|example| example.day == day && (example.part_1.is_some() || example.part_2.is_some()))
+}
+
+fn check_example(example: &Example) -> Result<usize, String> {
+    let solve = implemented_solver(example.day).map_err(|err| format!("{err:#}"))?;
+    let input = examples::text(example.day, example.variant);
+    let answer = solve(input).map_err(|err| format!("{err:#}"))?;
+    output::render(example.day, &answer, Format::Json)
+        .map_err(|err| format!("rendering as JSON: {err:#}"))?;
+
+    let expectations = [("part_1", example.part_1), ("part_2", example.part_2)];
+    let mut checked = 0;
+    for (name, expected) in expectations {
+        let Some(expected) = expected else {
+            continue;
+        };
+        match output::field(&answer, name) {
+            Some(actual) if actual == expected => checked += 1,
+            actual => {
+                return Err(format!(
+                    "{name}: expected {expected}, got {}",
+                    actual.as_deref().unwrap_or("nothing")
+                ))
+            }
+        }
+    }
+    Ok(checked)
+}
+
+/// Runs every day on its example and checks that the files the CLI reads are
+/// accessible. Returns whether everything passed.
+pub fn run(
+    puzzle_input_path: &Path,
+    input_dir: &Path,
+    config_path: Option<&Path>,
+    session: Option<&str>,
+    session_file: Option<&Path>,
+) -> bool {
+    let mut failures = 0;
+
+    for example in EXAMPLES {
+        let label = example.day.to_string();
+        match check_example(example) {
+            Ok(0) => println!("{label:<6} {SKIPPED}skipped{SKIPPED:#} (no expected answers)"),
+            Ok(1) => println!("{label:<6} {OK}ok{OK:#} (1 part)"),
+            Ok(checked) => println!("{label:<6} {OK}ok{OK:#} ({checked} parts)"),
+            Err(err) => {
+                failures += 1;
+                println!("{label:<6} {FAILED}FAILED{FAILED:#} {err}");
+            }
+        }
+    }
+
+    println!();
+    let config_loaded = check_config(config_path);
+    match input_dir.read_dir() {
+        Ok(_) => println!("input directory {} {OK}readable{OK:#}", input_dir.display()),
+        Err(err) => println!(
+            "input directory {} {SKIPPED}not readable{SKIPPED:#}: {err}",
+            input_dir.display()
+        ),
+    }
+    match File::open(puzzle_input_path) {
+        Ok(_) => println!(
+            "puzzle input {} {OK}readable{OK:#}",
+            puzzle_input_path.display()
+        ),
+        Err(err) => println!(
+            "puzzle input {} {SKIPPED}not readable{SKIPPED:#}: {err}",
+            puzzle_input_path.display()
+        ),
+    }
+    match session::resolve(session, session_file) {
+        Ok(token) => println!("session token {OK}found{OK:#} in {}", token.source),
+        Err(err) => println!("session token {SKIPPED}unavailable{SKIPPED:#}: {err}"),
+    }
+
+    println!();
+    if failures == 0 {
+        println!("{OK}all {} examples passed{OK:#}", EXAMPLES.len());
+    } else {
+        println!(
+            "{FAILED}{failures} of {} examples failed{FAILED:#}",
+            EXAMPLES.len()
+        );
+    }
+
+    failures == 0 && config_loaded
+}
+
+/// Reports whether the config file loads, returns `false` if it doesn't.
+fn check_config(path: Option<&Path>) -> bool {
+    let shown = path.unwrap_or(Path::new(config::DEFAULT_PATH)).display();
+    match Config::load(path) {
+        Ok(_) if path.is_none() && !Path::new(config::DEFAULT_PATH).exists() => {
+            println!("config {shown} {SKIPPED}not found{SKIPPED:#}, using defaults");
+            true
+        }
+        Ok(_) => {
+            println!("config {shown} {OK}loaded{OK:#}");
+            true
+        }
+        Err(err) => {
+            println!("config {shown} {FAILED}FAILED{FAILED:#} {err:#}");
+            false
+        }
+    }
+}
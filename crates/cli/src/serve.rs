@@ -0,0 +1,145 @@
+//! `serve`: the solvers behind HTTP, for dashboards and the like. Needs the
+//! `serve` feature.
+//!
+//! - `POST /solve/{day}` with the puzzle input as the body answers with
+//!   `{ day, title, answer, timing: { parse_ns, solve_ns } }`.
+//! - `GET /days` lists the implemented days as `{ day, title }`.
+//!
+//! Errors come as `{ error, message }`, `error` being one of the kinds the
+//! CLI reports with `--output json`.
+
+use std::net::SocketAddr;
+
+#[cfg(feature = "serve")]
+pub fn run(addr: SocketAddr) -> anyhow::Result<()> {
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        eprintln!("listening on http://{}", listener.local_addr()?);
+        axum::serve(listener, server::router()).await?;
+        Ok(())
+    })
+}
+
+#[cfg(not(feature = "serve"))]
+pub fn run(_addr: SocketAddr) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "`serve` needs the HTTP server, build with `--features serve`"
+    ))
+}
+
+#[cfg(feature = "serve")]
+mod server {
+    use std::time::Instant;
+
+    use aoc_2024_core::{day::Day, error::SolveError, phase, registry};
+    use axum::{
+        extract::Path,
+        http::StatusCode,
+        response::{IntoResponse, Response},
+        routing::{get, post},
+        Json, Router,
+    };
+
+    pub fn router() -> Router {
+        Router::new()
+            .route("/solve/{day}", post(solve))
+            .route("/days", get(days))
+    }
+
+    #[derive(Debug, serde::Serialize)]
+    struct Solved {
+        day: u8,
+        title: &'static str,
+        answer: serde_json::Value,
+        timing: Timing,
+    }
+
+    #[derive(Debug, serde::Serialize)]
+    struct Timing {
+        parse_ns: u128,
+        solve_ns: u128,
+    }
+
+    #[derive(Debug, serde::Serialize)]
+    struct Failure {
+        #[serde(skip)]
+        status: StatusCode,
+        error: &'static str,
+        message: String,
+    }
+
+    impl Failure {
+        fn new(status: StatusCode, error: &'static str, message: impl ToString) -> Self {
+            Self {
+                status,
+                error,
+                message: message.to_string(),
+            }
+        }
+    }
+
+    impl IntoResponse for Failure {
+        fn into_response(self) -> Response {
+            (self.status, Json(&self)).into_response()
+        }
+    }
+
+    async fn solve(Path(day): Path<String>, input: String) -> Result<Json<Solved>, Failure> {
+        let day: Day = day
+            .parse()
+            .map_err(|err| Failure::new(StatusCode::NOT_FOUND, "usage", err))?;
+        let solver = registry::solver(day).ok_or_else(|| {
+            Failure::new(
+                StatusCode::NOT_FOUND,
+                "usage",
+                format!("{day} isn't implemented"),
+            )
+        })?;
+
+        // Solving takes a while and may use rayon, keep it off the threads
+        // that serve requests.
+        let (answer, timing) = tokio::task::spawn_blocking(move || {
+            phase::take_parsing_time();
+            let start = Instant::now();
+            let answer = solver.solve(&input);
+            let parse = phase::take_parsing_time();
+            let solve = start.elapsed().saturating_sub(parse);
+            let timing = Timing {
+                parse_ns: parse.as_nanos(),
+                solve_ns: solve.as_nanos(),
+            };
+            (answer, timing)
+        })
+        .await
+        .map_err(|err| Failure::new(StatusCode::INTERNAL_SERVER_ERROR, "other", err))?;
+
+        let answer = answer.map_err(|err| match err {
+            SolveError::Parse(err) => Failure::new(StatusCode::UNPROCESSABLE_ENTITY, "parse", err),
+            err => Failure::new(StatusCode::UNPROCESSABLE_ENTITY, "unsolvable", err),
+        })?;
+        Ok(Json(Solved {
+            day: day.number(),
+            title: solver.title(),
+            answer,
+            timing,
+        }))
+    }
+
+    #[derive(Debug, serde::Serialize)]
+    struct Entry {
+        day: u8,
+        title: &'static str,
+    }
+
+    async fn days() -> Json<Vec<Entry>> {
+        Json(
+            registry::SOLVERS
+                .iter()
+                .map(|solver| Entry {
+                    day: solver.day().number(),
+                    title: solver.title(),
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,40 @@
+use anstream::println;
+use anstyle::{AnsiColor, Style};
+use aoc_2024_core::{day::Day, IMPLEMENTED_DAYS};
+use aoc_2024_net::{aoc_client::AocClient, session::SessionToken};
+
+use crate::selftest;
+
+const WARNING: Style = AnsiColor::Yellow.on_default().bold();
+
+/// Prints the stars earned on adventofcode.com next to what this crate
+/// implements, and calls out days where the two disagree.
+pub fn print(session: SessionToken) -> anyhow::Result<()> {
+    let stars = AocClient::new(session).stars()?;
+
+    println!("day  stars  implemented  example checked");
+    for day in Day::all() {
+        let implemented = IMPLEMENTED_DAYS.contains(&day);
+        let stars = stars.get(&day).copied().unwrap_or(0);
+
+        let note = match (stars, implemented) {
+            (0, false) => continue,
+            (0, true) => "  not solved on the site",
+            (_, false) => "  solved on the site, missing here",
+            _ => "",
+        };
+        println!(
+            "{:>3}  {:<5}  {:<11}  {:<15}{WARNING}{note}{WARNING:#}",
+            day.number(),
+            "*".repeat(stars.into()),
+            if implemented { "yes" } else { "no" },
+            if selftest::has_expected_answers(day) {
+                "yes"
+            } else {
+                "no"
+            },
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,379 @@
+//! `tui`: every implemented day in a terminal UI, to solve them one after the
+//! other and look around their maps. Needs the `tui` feature.
+//!
+//! Up and down pick a day and Enter solves it on `<input_dir>/dayN.txt`. `v`
+//! and `V` load part 1 or 2 of the day's visualization into the map viewer,
+//! Tab moves between the list of days and the map viewer. In the map viewer
+//! the arrow keys pan, `+` and `-` zoom and `[` and `]` go through the
+//! frames. `q` quits.
+
+use std::path::Path;
+
+#[cfg(feature = "tui")]
+pub fn run(input_dir: &Path) -> anyhow::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = app::App::new(input_dir).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(not(feature = "tui"))]
+pub fn run(_input_dir: &Path) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "`tui` needs the terminal UI, build with `--features tui`"
+    ))
+}
+
+#[cfg(feature = "tui")]
+mod app {
+    use std::{
+        fs::read_to_string,
+        path::{Path, PathBuf},
+        time::{Duration, Instant},
+    };
+
+    use aoc_2024_core::{
+        phase,
+        registry::{Solver, SOLVERS},
+        viz,
+    };
+    use itertools::Itertools;
+    use ratatui::{
+        crossterm::event::{self, Event, KeyCode, KeyEventKind},
+        layout::{Constraint, Layout, Rect},
+        style::{Style, Stylize},
+        text::Line,
+        widgets::{Block, List, ListState, Paragraph},
+        DefaultTerminal, Frame,
+    };
+
+    use crate::visualizer;
+
+    /// Frames kept of a visualization, long ones like day 9's have tens of
+    /// thousands.
+    const MAX_FRAMES: usize = 256;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Focus {
+        Days,
+        Map,
+    }
+
+    #[derive(Debug)]
+    struct Solved {
+        answer: Result<serde_json::Value, String>,
+        parse: Duration,
+        solve: Duration,
+    }
+
+    #[derive(Debug)]
+    struct Map {
+        part: u8,
+        frames: Vec<String>,
+        frame: usize,
+        /// Lines and columns scrolled down and right.
+        scroll: (u16, u16),
+        /// Every tile is drawn `zoom + 1` times over when positive, and only
+        /// every `1 - zoom`th one when negative.
+        zoom: i8,
+    }
+
+    pub struct App {
+        input_dir: PathBuf,
+        days: ListState,
+        solved: Vec<Option<Solved>>,
+        /// Of the selected day.
+        map: Option<Result<Map, String>>,
+        focus: Focus,
+    }
+
+    impl App {
+        pub fn new(input_dir: &Path) -> Self {
+            Self {
+                input_dir: input_dir.to_owned(),
+                days: ListState::default().with_selected(Some(0)),
+                solved: SOLVERS.iter().map(|_| None).collect(),
+                map: None,
+                focus: Focus::Days,
+            }
+        }
+
+        pub fn run(mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+            loop {
+                terminal.draw(|frame| self.draw(frame))?;
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match (self.focus, key.code) {
+                    (_, KeyCode::Char('q') | KeyCode::Esc) => return Ok(()),
+                    (_, KeyCode::Tab) => {
+                        self.focus = match self.focus {
+                            Focus::Days => Focus::Map,
+                            Focus::Map => Focus::Days,
+                        }
+                    }
+                    (_, KeyCode::Enter) => self.solve(),
+                    (_, KeyCode::Char('v')) => self.visualize(1),
+                    (_, KeyCode::Char('V')) => self.visualize(2),
+                    (Focus::Days, KeyCode::Up) => self.select(-1),
+                    (Focus::Days, KeyCode::Down) => self.select(1),
+                    (Focus::Map, code) => {
+                        if let Some(Ok(map)) = &mut self.map {
+                            map.handle(code);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        fn selected(&self) -> (usize, &'static dyn Solver) {
+            let idx = self.days.selected().unwrap_or(0);
+            (idx, SOLVERS[idx])
+        }
+
+        fn input_path(&self, solver: &dyn Solver) -> PathBuf {
+            self.input_dir.join(format!("{}.txt", solver.day()))
+        }
+
+        fn select(&mut self, by: isize) {
+            let idx = self.selected().0.saturating_add_signed(by);
+            self.days.select(Some(idx.min(SOLVERS.len() - 1)));
+            self.map = None;
+        }
+
+        fn solve(&mut self) {
+            let (idx, solver) = self.selected();
+            let input_path = self.input_path(solver);
+            let solved = match read_to_string(&input_path) {
+                Ok(input) => {
+                    phase::take_parsing_time();
+                    let start = Instant::now();
+                    let answer = solver.solve(&input).map_err(|err| err.to_string());
+                    let parse = phase::take_parsing_time();
+                    Solved {
+                        answer,
+                        parse,
+                        solve: start.elapsed().saturating_sub(parse),
+                    }
+                }
+                Err(err) => Solved {
+                    answer: Err(format!("failed to read {}: {err}", input_path.display())),
+                    parse: Duration::ZERO,
+                    solve: Duration::ZERO,
+                },
+            };
+            self.solved[idx] = Some(solved);
+        }
+
+        fn visualize(&mut self, part: u8) {
+            let (_, solver) = self.selected();
+            let day = solver.day();
+            let map = match visualizer(day) {
+                None => Err(format!("{day} has no visualization")),
+                Some(visualize) => {
+                    let input_path = self.input_path(solver);
+                    read_to_string(&input_path)
+                        .map_err(|err| format!("failed to read {}: {err}", input_path.display()))
+                        .and_then(|input| {
+                            let mut frames = Frames::default();
+                            let options = viz::Options { part, color: false };
+                            visualize(&input, &options, &mut frames)
+                                .map_err(|err| err.to_string())?;
+                            let frames = frames.into_frames();
+                            if frames.is_empty() {
+                                return Err("the visualization has no frames".to_owned());
+                            }
+                            Ok(Map {
+                                part,
+                                frames,
+                                frame: 0,
+                                scroll: (0, 0),
+                                zoom: 0,
+                            })
+                        })
+                }
+            };
+            self.map = Some(map);
+            self.focus = Focus::Map;
+        }
+
+        fn draw(&mut self, frame: &mut Frame) {
+            let [days_area, day_area] =
+                Layout::horizontal([Constraint::Length(36), Constraint::Min(0)])
+                    .areas(frame.area());
+            let [details_area, map_area] =
+                Layout::vertical([Constraint::Length(8), Constraint::Min(0)]).areas(day_area);
+
+            let focused = |focus| {
+                if self.focus == focus {
+                    Style::new().bold()
+                } else {
+                    Style::new().dim()
+                }
+            };
+
+            let days = List::new(SOLVERS.iter().zip(&self.solved).map(|(solver, solved)| {
+                let mark = match solved {
+                    Some(Solved { answer: Ok(_), .. }) => "✓",
+                    Some(Solved { answer: Err(_), .. }) => "✗",
+                    None => " ",
+                };
+                format!("{mark} {:>2} {}", solver.day().number(), solver.title())
+            }))
+            .block(
+                Block::bordered()
+                    .title(" Days ")
+                    .border_style(focused(Focus::Days)),
+            )
+            .highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(days, days_area, &mut self.days);
+
+            self.draw_details(frame, details_area);
+            self.draw_map(frame, map_area, focused(Focus::Map));
+        }
+
+        fn draw_details(&self, frame: &mut Frame, area: Rect) {
+            let (idx, solver) = self.selected();
+            let lines = match &self.solved[idx] {
+                None => vec![Line::from(format!(
+                    "Enter solves {}",
+                    self.input_path(solver).display()
+                ))],
+                Some(solved) => {
+                    let mut lines = vec![Line::from(format!(
+                        "parse {:.2?}, solve {:.2?}",
+                        solved.parse, solved.solve
+                    ))];
+                    match &solved.answer {
+                        Ok(serde_json::Value::Object(parts)) => {
+                            lines.extend(
+                                parts
+                                    .iter()
+                                    .map(|(part, answer)| Line::from(format!("{part}: {answer}"))),
+                            );
+                        }
+                        Ok(answer) => lines.push(Line::from(answer.to_string())),
+                        Err(err) => lines.push(Line::from(err.as_str()).red()),
+                    }
+                    lines
+                }
+            };
+            let title = format!(" {}: {} ", solver.day(), solver.title());
+            frame.render_widget(
+                Paragraph::new(lines).block(Block::bordered().title(title)),
+                area,
+            );
+        }
+
+        fn draw_map(&self, frame: &mut Frame, area: Rect, border_style: Style) {
+            let block = Block::bordered().border_style(border_style);
+            let paragraph = match &self.map {
+                None => Paragraph::new("v shows part 1 of the visualization, V part 2")
+                    .block(block.title(" Map ")),
+                Some(Err(err)) => Paragraph::new(err.as_str().red()).block(block.title(" Map ")),
+                Some(Ok(map)) => {
+                    let title = format!(
+                        " Part {}, frame {}/{}, zoom {} ",
+                        map.part,
+                        map.frame + 1,
+                        map.frames.len(),
+                        map.zoom
+                    );
+                    Paragraph::new(zoom(&map.frames[map.frame], map.zoom))
+                        .scroll(map.scroll)
+                        .block(block.title(title))
+                }
+            };
+            frame.render_widget(paragraph, area);
+        }
+    }
+
+    impl Map {
+        fn handle(&mut self, code: KeyCode) {
+            let (rows, cols) = &mut self.scroll;
+            match code {
+                KeyCode::Up => *rows = rows.saturating_sub(1),
+                KeyCode::Down => *rows = rows.saturating_add(1),
+                KeyCode::Left => *cols = cols.saturating_sub(2),
+                KeyCode::Right => *cols = cols.saturating_add(2),
+                KeyCode::Char('+') => self.zoom = (self.zoom + 1).min(3),
+                KeyCode::Char('-') => self.zoom = (self.zoom - 1).max(-4),
+                KeyCode::Char('[') => self.frame = self.frame.saturating_sub(1),
+                KeyCode::Char(']') => self.frame = (self.frame + 1).min(self.frames.len() - 1),
+                _ => {}
+            }
+        }
+    }
+
+    fn zoom(frame: &str, zoom: i8) -> String {
+        if zoom >= 0 {
+            let times = zoom as usize + 1;
+            frame
+                .lines()
+                .map(|line| {
+                    line.chars()
+                        .flat_map(|ch| itertools::repeat_n(ch, times))
+                        .collect::<String>()
+                })
+                .flat_map(|line| itertools::repeat_n(line, times))
+                .join("\n")
+        } else {
+            let step = 1 + zoom.unsigned_abs() as usize;
+            frame
+                .lines()
+                .step_by(step)
+                .map(|line| line.chars().step_by(step).collect::<String>())
+                .join("\n")
+        }
+    }
+
+    /// Keeps up to [`MAX_FRAMES`] frames spread evenly over the
+    /// visualization, and the last one.
+    #[derive(Debug)]
+    struct Frames {
+        kept: Vec<String>,
+        /// Only every `stride`th frame is kept.
+        stride: usize,
+        seen: usize,
+        last: Option<String>,
+    }
+
+    impl Default for Frames {
+        fn default() -> Self {
+            Self {
+                kept: vec![],
+                stride: 1,
+                seen: 0,
+                last: None,
+            }
+        }
+    }
+
+    impl Frames {
+        fn into_frames(mut self) -> Vec<String> {
+            self.kept.extend(self.last);
+            self.kept
+        }
+    }
+
+    impl viz::Sink for Frames {
+        fn frame(&mut self, frame: &str) -> std::io::Result<()> {
+            if self.seen.is_multiple_of(self.stride) && self.kept.len() == MAX_FRAMES {
+                self.kept = self.kept.drain(..).step_by(2).collect();
+                self.stride *= 2;
+            }
+            if self.seen.is_multiple_of(self.stride) {
+                self.kept.push(frame.to_owned());
+                self.last = None;
+            } else {
+                self.last = Some(frame.to_owned());
+            }
+            self.seen += 1;
+            Ok(())
+        }
+    }
+}
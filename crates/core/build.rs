@@ -0,0 +1,80 @@
+use std::{env, fs, path::Path};
+
+/// Finds every `src/day_N.rs` and generates `$OUT_DIR/days.rs`, which
+/// declares the modules and a `with_days!` macro listing them, so adding a
+/// day module is all it takes to make it runnable. Also embeds every
+/// `src/examples/dayN/<variant>.txt` into `$OUT_DIR/examples.rs`.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_dir = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut days = fs::read_dir(&src_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let file_name = entry.unwrap().file_name().into_string().ok()?;
+            file_name
+                .strip_prefix("day_")?
+                .strip_suffix(".rs")?
+                .parse::<u8>()
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+
+    let mut generated = String::new();
+    for day in &days {
+        let path = src_dir.join(format!("day_{day}.rs"));
+        generated += &format!(
+            "#[path = {:?}]\npub mod day_{day};\n",
+            path.display().to_string()
+        );
+    }
+
+    generated += "\n/// Calls `$callback!` with `(number, module)` for every day module.\n";
+    generated += "#[macro_export]\nmacro_rules! with_days {\n    ($callback:ident) => {\n        $callback! {\n";
+    for day in &days {
+        generated += &format!("            ({day}, day_{day}),\n");
+    }
+    generated += "        }\n    };\n}\n";
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), generated).unwrap();
+    fs::write(
+        Path::new(&out_dir).join("examples.rs"),
+        examples(&src_dir.join("examples")),
+    )
+    .unwrap();
+}
+
+/// A slice of `(day, variant, text)` for every example file, sorted.
+fn examples(examples_dir: &Path) -> String {
+    let mut examples = Vec::new();
+    for day_entry in fs::read_dir(examples_dir).unwrap() {
+        let day_dir = day_entry.unwrap().path();
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        for entry in fs::read_dir(&day_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                let variant = path.file_stem().unwrap().to_str().unwrap().to_owned();
+                examples.push((day, variant, path));
+            }
+        }
+    }
+    examples.sort();
+
+    let mut generated = String::from("&[\n");
+    for (day, variant, path) in examples {
+        generated += &format!(
+            "    ({day}, {variant:?}, include_str!({:?})),\n",
+            path.display().to_string()
+        );
+    }
+    generated += "]\n";
+    generated
+}
@@ -0,0 +1,48 @@
+//! Solves the examples of every implemented day through the public API and
+//! prints the answers, a quick end-to-end check that needs no puzzle inputs:
+//!
+//! ```sh
+//! cargo run -p aoc-2024-core --example smoke
+//! ```
+//!
+//! Only failures make it exit with an error, whether the answers are the
+//! puzzle's is up to the tests and `aoc-2024 selftest`.
+
+use std::process::ExitCode;
+
+use aoc_2024_core::{examples, registry};
+
+fn main() -> ExitCode {
+    let mut failures = 0;
+    for solver in registry::SOLVERS {
+        let day = solver.day();
+        for (variant, input) in examples::all(day) {
+            let day = day.to_string();
+            match solver.solve(input) {
+                Ok(answer) => {
+                    let parts = answer
+                        .as_object()
+                        .into_iter()
+                        .flatten()
+                        .map(|(part, value)| format!("{part}={value}"))
+                        .collect::<Vec<_>>();
+                    println!(
+                        "{day:<6}{variant:<10} {:<21} {}",
+                        solver.title(),
+                        parts.join(" ")
+                    );
+                }
+                Err(err) => {
+                    failures += 1;
+                    println!("{day:<6}{variant:<10} {:<21} failed: {err}", solver.title());
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{failures} examples failed");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,40 @@
+/* The C interface of aoc-2024-core, built with the `ffi` feature, see
+ * src/ffi.rs. */
+
+#ifndef AOC2024_H
+#define AOC2024_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+enum {
+    AOC2024_OK = 0,
+    AOC2024_NULL_POINTER = 1,
+    AOC2024_INVALID_DAY = 2,
+    AOC2024_NOT_IMPLEMENTED = 3,
+    AOC2024_INVALID_PART = 4,
+    AOC2024_INVALID_UTF8 = 5,
+    AOC2024_INVALID_INPUT = 6,
+    /* 7 was for any input without a solution, which now each have their own
+     * code from 10 on. */
+    AOC2024_BUFFER_TOO_SMALL = 8,
+    AOC2024_PANIC = 9,
+    /* Day 5: the ordering rules for an update go round in a circle. */
+    AOC2024_RULE_CYCLE = 10,
+    /* Day 5: the ordering rules leave the order of some pages open. */
+    AOC2024_UNDECIDED_ORDER = 11,
+    /* Day 13: the numbers get too large to work with. */
+    AOC2024_NUMERIC_OVERFLOW = 12,
+    /* Day 14: the robots never all stand on tiles of their own. */
+    AOC2024_ROBOTS_OVERLAP = 13,
+    /* Day 16: there's no way through the maze. */
+    AOC2024_UNREACHABLE = 14,
+};
+
+/* Solves part `part` of `day` for the `len` bytes at `input` and writes the
+ * answer into `out_buf` as NUL-terminated text. Returns one of the codes
+ * above. */
+int aoc2024_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t len,
+                  char *out_buf, size_t out_len);
+
+#endif
@@ -0,0 +1,111 @@
+//! What the parts of a day's answer can be. Every `Answer` struct is made of
+//! these, so that all days serialize, print and compare the same way whether
+//! an answer is a number or text.
+
+use std::fmt::{self, Display};
+
+/// One part of an answer. Serializes as the bare number or string, the way
+/// adventofcode.com wants it entered.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnswerValue {
+    Int(i128),
+    UInt(u128),
+    Text(String),
+}
+
+impl Display for AnswerValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerValue::Int(value) => write!(f, "{value}"),
+            AnswerValue::UInt(value) => write!(f, "{value}"),
+            AnswerValue::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Numbers past 64 bits, which JSON and YAML readers tend not to take, are
+/// written as strings of their digits.
+impl serde::Serialize for AnswerValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AnswerValue::Int(value) => match i64::try_from(*value) {
+                Ok(value) => serializer.serialize_i64(value),
+                Err(_) => serializer.collect_str(value),
+            },
+            AnswerValue::UInt(value) => match u64::try_from(*value) {
+                Ok(value) => serializer.serialize_u64(value),
+                Err(_) => serializer.collect_str(value),
+            },
+            AnswerValue::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+impl From<String> for AnswerValue {
+    fn from(text: String) -> Self {
+        AnswerValue::Text(text)
+    }
+}
+
+impl From<&str> for AnswerValue {
+    fn from(text: &str) -> Self {
+        AnswerValue::Text(text.to_owned())
+    }
+}
+
+/// Conversions from the integer types the days answer with, and comparing
+/// with them both ways round, so that tests can check answers against the
+/// plain numbers the puzzle gives.
+macro_rules! integers {
+    ($variant:ident($wide:ty): $($int:ty),*) => {
+        $(
+            impl From<$int> for AnswerValue {
+                fn from(value: $int) -> Self {
+                    AnswerValue::$variant(<$wide>::try_from(value).unwrap())
+                }
+            }
+
+            impl PartialEq<$int> for AnswerValue {
+                fn eq(&self, other: &$int) -> bool {
+                    match self {
+                        AnswerValue::$variant(value) => *value == <$wide>::try_from(*other).unwrap(),
+                        _ => false,
+                    }
+                }
+            }
+
+            impl PartialEq<AnswerValue> for $int {
+                fn eq(&self, other: &AnswerValue) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+integers!(Int(i128): i64, i128);
+integers!(UInt(u128): u64, usize, u128);
+
+#[test]
+fn conversions() {
+    assert_eq!(AnswerValue::Int(-3), AnswerValue::from(-3i64));
+    assert_eq!(AnswerValue::UInt(7), AnswerValue::from(7usize));
+    assert_eq!(AnswerValue::from(u128::MAX), u128::MAX);
+    assert_eq!(5u64, AnswerValue::from(5u64));
+    assert_ne!(AnswerValue::from("5"), AnswerValue::from(5u64));
+    assert_eq!("6,0,1", AnswerValue::from("6,0,1").to_string());
+}
+
+#[test]
+fn serializing() {
+    assert_eq!(
+        serde_json::json!([-3, 7, "6,0,1", "340282366920938463463374607431768211455"]),
+        serde_json::to_value([
+            AnswerValue::from(-3i64),
+            AnswerValue::from(7u64),
+            AnswerValue::from("6,0,1"),
+            AnswerValue::from(u128::MAX),
+        ])
+        .unwrap()
+    );
+}
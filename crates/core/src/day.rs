@@ -0,0 +1,132 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A day of the event, always within 1 to 25.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(u8);
+
+impl Day {
+    pub const FIRST: Day = Day(1);
+    pub const LAST: Day = Day(25);
+
+    /// Panics if `number` is out of 1 to 25, use `Day::try_from` for numbers
+    /// that aren't known to be valid.
+    pub const fn new(number: u8) -> Day {
+        assert!(
+            number >= Self::FIRST.0 && number <= Self::LAST.0,
+            "days go from 1 to 25"
+        );
+        Day(number)
+    }
+
+    pub const fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Every day of the event in order.
+    pub fn all() -> impl Iterator<Item = Day> {
+        (Self::FIRST.0..=Self::LAST.0).map(Day)
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{}", self.0)
+    }
+}
+
+/// A number or name that doesn't stand for a day of the event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDay {
+    pub input: String,
+}
+
+impl Display for InvalidDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} isn't a day of the event, expected 1 to 25 as in `5`, `day5` or `day_05`",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for InvalidDay {}
+
+impl TryFrom<u8> for Day {
+    type Error = InvalidDay;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        if (Self::FIRST.0..=Self::LAST.0).contains(&number) {
+            Ok(Day(number))
+        } else {
+            Err(InvalidDay {
+                input: number.to_string(),
+            })
+        }
+    }
+}
+
+impl FromStr for Day {
+    type Err = InvalidDay;
+
+    /// Accepts `5`, `05`, `day5`, `day05`, `day_5` and `day_05`, ignoring
+    /// case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDay {
+            input: s.to_string(),
+        };
+        let lowercase = s.to_ascii_lowercase();
+        let digits = match lowercase.strip_prefix("day") {
+            Some(rest) => rest.strip_prefix('_').unwrap_or(rest),
+            None => &lowercase,
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        digits
+            .parse::<u8>()
+            .ok()
+            .and_then(|number| Day::try_from(number).ok())
+            .ok_or_else(invalid)
+    }
+}
+
+#[test]
+fn parse() {
+    for s in [
+        "5", "05", "day5", "day05", "day_5", "day_05", "Day5", "DAY_05",
+    ] {
+        assert_eq!(Ok(Day::new(5)), s.parse(), "{s}");
+    }
+    assert_eq!(Ok(Day::LAST), "day25".parse());
+
+    for s in [
+        "", "0", "26", "day", "day_", "day-5", "+5", "5a", "day__5", "300",
+    ] {
+        assert_eq!(
+            Err(InvalidDay {
+                input: s.to_string()
+            }),
+            s.parse::<Day>(),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(25, Day::all().count());
+    assert_eq!(Some(Day::FIRST), Day::all().next());
+    assert!(Day::try_from(0).is_err());
+    assert!(Day::try_from(26).is_err());
+    assert_eq!("day7", Day::new(7).to_string());
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    Day::new(26);
+}
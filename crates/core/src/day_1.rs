@@ -0,0 +1,161 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    incremental::Incremental,
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Historian Hysteria";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let (left_list, right_list) = phase::parsing(|| parse(input))?;
+    tracing::debug!(pairs = left_list.len(), "parsed location lists");
+    Ok(Answer {
+        part_1: solution::total_distance(&left_list, &right_list).into(),
+        part_2: solution::similarity_score(&left_list, &right_list).into(),
+    })
+}
+
+/// Parses the two lists, which is all there is to check about them.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let (left_list, _) = parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!("{} pairs of location IDs", left_list.len()));
+    Ok(report)
+}
+
+pub fn incremental_solution(
+    state: &mut Incremental<(i64, i64)>,
+    input: &str,
+) -> Result<Answer, SolveError> {
+    let (left_list, right_list): (Vec<i64>, Vec<i64>) = state
+        .update(input, |line| parse_complete(parser::line(), line))?
+        .iter()
+        .copied()
+        .unzip();
+    Ok(Answer {
+        part_1: solution::total_distance(&left_list, &right_list).into(),
+        part_2: solution::similarity_score(&left_list, &right_list).into(),
+    })
+}
+
+/// The left and right lists of location IDs.
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::parse_util::{lines, Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, (Vec<i64>, Vec<i64>)> {
+        lines(line::<'a>()).map(|v: Vec<(i64, i64)>| v.into_iter().unzip())
+    }
+
+    pub fn line<'a>() -> impl Parser<'a, (i64, i64)> {
+        nom::sequence::separated_pair(
+            nom::character::complete::i64,
+            nom::character::complete::space1,
+            nom::character::complete::i64,
+        )
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(1), |rng| {
+            format!(
+                "{}   {}",
+                rng.between(10000, 99999),
+                rng.between(10000, 99999)
+            )
+        });
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::collections::BTreeMap;
+
+    use itertools::Itertools;
+
+    pub fn total_distance(left_list: &[i64], right_list: &[i64]) -> i64 {
+        left_list
+            .iter()
+            .sorted()
+            .zip(right_list.iter().sorted())
+            .map(|(l, r)| (l - r).abs())
+            .sum()
+    }
+
+    pub fn similarity_score(left_list: &[i64], right_list: &[i64]) -> i64 {
+        let freqs = right_list
+            .into_iter()
+            .fold(BTreeMap::<i64, i64>::new(), |mut acc, num| {
+                acc.entry(*num).and_modify(|x| *x += 1).or_insert(1);
+                acc
+            });
+        left_list
+            .into_iter()
+            .map(|num| num * freqs.get(&num).unwrap_or(&0))
+            .sum()
+    }
+
+    #[test]
+    fn example() {
+        let (left_list, right_list) = super::example::intermediate();
+        assert_eq!(
+            total_distance(&left_list, &right_list),
+            super::example::output_total_distance()
+        );
+        assert_eq!(
+            similarity_score(&left_list, &right_list),
+            super::example::output_similarity_score()
+        );
+    }
+
+    #[test]
+    fn example_incremental() {
+        let input = super::example::input();
+        let mut state = super::Incremental::new();
+
+        for len in [0, 5, 12, input.len()] {
+            super::incremental_solution(&mut state, &input[..len]).unwrap();
+        }
+
+        let answer = super::incremental_solution(&mut state, input).unwrap();
+        assert_eq!(answer.part_1, super::example::output_total_distance());
+        assert_eq!(answer.part_2, super::example::output_similarity_score());
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(1), "example")
+    }
+
+    pub fn intermediate() -> (Vec<i64>, Vec<i64>) {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_total_distance() -> i64 {
+        11
+    }
+
+    pub fn output_similarity_score() -> i64 {
+        31
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_total_distance(), part_2: output_similarity_score());
+}
@@ -0,0 +1,350 @@
+use anstyle::AnsiColor;
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    grid::{Grid, Position},
+    phase,
+    validate::{grid_shape, Report},
+    viz,
+};
+
+pub const TITLE: &str = "Hoof It";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(size = ?input.size(), "parsed topographic map");
+
+    Ok(Answer {
+        part_1: solution::total_score_of_topographic_map(&input).into(),
+        part_2: solution::total_rating_of_topographic_map(&input).into(),
+    })
+}
+
+/// Checks that the grid is rectangular and parses it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    if grid_shape(input, &mut report) {
+        parse(input)?;
+    }
+    Ok(report)
+}
+
+/// Where the height is 0, in row order.
+pub fn trailheads(map: &Grid<u8>) -> Vec<Position> {
+    solution::trailheads(map)
+}
+
+/// Every trail from `trailhead` up to a height of 9, as the positions along it
+/// starting with `trailhead`. Their number is the trailhead's rating in part
+/// 2, the number of different summits they end on its score in part 1.
+pub fn trails(map: &Grid<u8>, trailhead: Position) -> Vec<Vec<Position>> {
+    solution::trails(map, trailhead)
+}
+
+/// Shows the trails of one trailhead after the other, with only the heights
+/// along them on the map. With `options.color` the trailhead and the summits
+/// stand out.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let map = parse(input)?;
+    let trailhead_style = AnsiColor::Yellow.on_default().bold();
+    let summit_style = AnsiColor::Green.on_default().bold();
+
+    for (trailhead_idx, trailhead) in trailheads(&map).into_iter().enumerate() {
+        let trails = trails(&map, trailhead);
+        let mut on_trail = Grid::fill_with(false, map.size());
+        trails
+            .iter()
+            .flatten()
+            .for_each(|position| on_trail[*position] = true);
+        let summits = trails
+            .iter()
+            .filter_map(|trail| trail.last())
+            .unique()
+            .count();
+
+        let tiles = on_trail
+            .iter()
+            .map(|(position, on_trail)| {
+                let height = map[position];
+                let tile = if *on_trail {
+                    char::from_digit(height.into(), 10).unwrap()
+                } else {
+                    '.'
+                };
+                let style = match height {
+                    0 if *on_trail => trailhead_style,
+                    9 if *on_trail => summit_style,
+                    _ => return tile.to_string(),
+                };
+                if options.color {
+                    format!("{style}{tile}{style:#}")
+                } else {
+                    tile.to_string()
+                }
+            })
+            .chunks(map.size().1)
+            .into_iter()
+            .map(|mut row| row.join(""))
+            .join("\n");
+        let Position {
+            row_index,
+            col_index,
+        } = trailhead;
+        sink.frame(&format!(
+            "Trailhead {trailhead_idx} at {col_index},{row_index}: score {summits}, rating {}\n{tiles}",
+            trails.len()
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// The heights of the topographic map.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::{grid::Grid, parse_util::Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, Grid<u8>> {
+        Grid::parse(height())
+    }
+
+    fn height<'a>() -> impl Parser<'a, u8> {
+        const RADIX: u32 = 10;
+        nom::character::complete::satisfy(|ch| ch.is_digit(RADIX))
+            .map(|ch: char| ch.to_digit(RADIX).unwrap().try_into().unwrap())
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(
+            &mut Rng::new(10),
+            |rng| char::from_digit(rng.below(10) as u32, 10).unwrap(),
+            &[],
+        );
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use itertools::Itertools;
+
+    use crate::{
+        grid::{Grid, Position},
+        search::flood_fill,
+    };
+
+    #[derive(Debug, Clone)]
+    #[repr(transparent)]
+    struct HeightMap(Grid<(u8, Vec<Position>)>);
+
+    impl HeightMap {
+        fn new(grid: &Grid<u8>) -> Self {
+            let height_and_neighbors = grid.positions().fold(
+                Grid::fill_with((0, vec![]), grid.size()),
+                |mut neighbors, current_position| {
+                    let current_height = grid[current_position];
+                    neighbors[current_position] = (
+                        current_height,
+                        grid.neighbor_cells4(current_position)
+                            .filter(|(_, height)| **height == current_height + 1)
+                            .map(|(position, _)| position)
+                            .collect_vec(),
+                    );
+                    neighbors
+                },
+            );
+
+            HeightMap(height_and_neighbors)
+        }
+
+        fn calculate_score_of_trailhead(
+            &self,
+            trailhead_position: Position,
+            unique_trail_ends: bool,
+        ) -> u64 {
+            let mut score = 0u64;
+
+            if unique_trail_ends {
+                // Every distinct trail counts, so positions get revisited.
+                let mut next_positions = vec![trailhead_position];
+                while let Some(current_position) = next_positions.pop() {
+                    let (current_height, current_neighbors) = &self.0[current_position];
+                    if *current_height == 9 {
+                        score += 1
+                    } else {
+                        next_positions.extend(current_neighbors)
+                    }
+                }
+            } else {
+                flood_fill(
+                    trailhead_position,
+                    |position| self.0[*position].1.iter().copied(),
+                    |position| {
+                        if self.0[*position].0 == 9 {
+                            score += 1
+                        }
+                    },
+                );
+            }
+
+            score
+        }
+
+        fn discover_trailheads<'a>(&'a self) -> impl 'a + Iterator<Item = Position> {
+            self.0
+                .iter()
+                .filter(|(_, (height, _))| *height == 0)
+                .map(|(position, _)| position)
+        }
+
+        fn calculate_total_score(&self, unique_trail_ends: bool) -> u64 {
+            self.discover_trailheads()
+                .map(|trailhead| self.calculate_score_of_trailhead(trailhead, unique_trail_ends))
+                .sum()
+        }
+    }
+
+    pub fn trailheads(grid: &Grid<u8>) -> Vec<Position> {
+        HeightMap::new(grid).discover_trailheads().collect()
+    }
+
+    pub fn trails(grid: &Grid<u8>, trailhead: Position) -> Vec<Vec<Position>> {
+        let height_map = HeightMap::new(grid);
+        let mut trails = vec![];
+        let mut partial_trails = vec![vec![trailhead]];
+        while let Some(trail) = partial_trails.pop() {
+            let (current_height, current_neighbors) = &height_map.0[*trail.last().unwrap()];
+            if *current_height == 9 {
+                trails.push(trail);
+                continue;
+            }
+            partial_trails.extend(current_neighbors.iter().map(|next_position| {
+                let mut trail = trail.clone();
+                trail.push(*next_position);
+                trail
+            }));
+        }
+        trails
+    }
+
+    pub fn total_score_of_topographic_map(grid: &Grid<u8>) -> u64 {
+        HeightMap::new(grid).calculate_total_score(false)
+    }
+
+    pub fn total_rating_of_topographic_map(grid: &Grid<u8>) -> u64 {
+        HeightMap::new(grid).calculate_total_score(true)
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            super::example::output_p_1(),
+            total_score_of_topographic_map(&super::example::intermediate())
+        );
+        assert_eq!(
+            super::example::output_p_2(),
+            total_rating_of_topographic_map(&super::example::intermediate())
+        );
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use itertools::Itertools;
+
+    use crate::{day::Day, examples};
+
+    use crate::grid::Grid;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(10), "example")
+    }
+
+    pub fn intermediate() -> Grid<u8> {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_p_1() -> u64 {
+        36
+    }
+
+    pub fn output_p_2() -> u64 {
+        81
+    }
+
+    #[test]
+    fn trails() {
+        let map = intermediate();
+        let trailheads = super::trailheads(&map);
+        assert_eq!(9, trailheads.len());
+        assert_eq!(crate::grid::Position::new(0, 2), trailheads[0]);
+
+        let trails = trailheads
+            .iter()
+            .map(|trailhead| super::trails(&map, *trailhead))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output_p_2(),
+            trails.iter().map(Vec::len).sum::<usize>() as u64
+        );
+        assert_eq!(
+            output_p_1(),
+            trails
+                .iter()
+                .map(|trails| trails.iter().map(|trail| trail.last()).unique().count())
+                .sum::<usize>() as u64
+        );
+        for trail in trails.iter().flatten() {
+            assert_eq!(
+                (0..=9).collect::<Vec<_>>(),
+                trail
+                    .iter()
+                    .map(|position| map[*position])
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn visualize() {
+        let mut frames = Vec::<String>::new();
+        let options = crate::viz::Options {
+            part: 1,
+            color: false,
+        };
+        super::visualize(input(), &options, &mut frames).unwrap();
+        assert_eq!(9, frames.len());
+        assert_eq!(
+            "Trailhead 0 at 2,0: score 5, rating 20\n\
+             .901....\n\
+             .812....\n\
+             8743....\n\
+             96549...\n\
+             ..6789..\n\
+             ....9...\n\
+             ........\n\
+             ........",
+            frames[0]
+        );
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
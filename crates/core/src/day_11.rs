@@ -0,0 +1,236 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Plutonian Pebbles";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    solution_with(input, Params::default())
+}
+
+/// What the puzzle text says rather than the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// How many times the stones blink for part 2, part 1 is always 25.
+    pub blinks: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { blinks: 75 }
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution_with(input: &str, params: Params) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(stones = input.len(), "parsed stones");
+
+    Ok(Answer {
+        part_1: solution::blink_n_times(&input, 25).into(),
+        part_2: solution::blink_n_times(&input, params.blinks).into(),
+    })
+}
+
+/// Parses the stones.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let stones = parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!("{} stones", stones.len()));
+    Ok(report)
+}
+
+/// Like [`solution_with`], but blinks all stones at once, see
+/// [`solution::blink_n_times_counting`]. It doesn't recurse, so it's fine with
+/// any number of blinks.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn counting_solution(input: &str, params: Params) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(stones = input.len(), "parsed stones");
+
+    Ok(Answer {
+        part_1: solution::blink_n_times_counting(&input, 25).into(),
+        part_2: solution::blink_n_times_counting(&input, params.blinks).into(),
+    })
+}
+
+/// Part 1 from blinking the actual row of stones, which `--self-check`
+/// compares [`solution`] with. The row grows far too long for 75 blinks, so
+/// part 2 comes from [`counting_solution`], a second opinion at least.
+#[cfg(any(test, feature = "reference"))]
+pub fn reference_solution(input: &str) -> Result<Answer, SolveError> {
+    let input = parse(input)?;
+    Ok(Answer {
+        part_1: solution::blink_n_times_one_by_one(&input, 25).into(),
+        part_2: solution::blink_n_times_counting(&input, 75).into(),
+    })
+}
+
+/// The numbers engraved on the stones.
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_complete(parser::input, input)
+}
+
+pub mod parser {
+    use crate::parse_util::{bytes, Parser};
+
+    pub fn input(input: &str) -> nom::IResult<&str, Vec<u64>> {
+        bytes(nom::multi::separated_list1(
+            nom::character::complete::space1,
+            nom::character::complete::u64,
+        ))
+        .parse(input)
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{Rng, LINES};
+
+        let mut rng = Rng::new(11);
+        let generated = (0..LINES)
+            .map(|_| rng.between(0, 1 << 30).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        b.iter(|| input(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::collections::HashMap;
+
+    use crate::memo::Memo;
+
+    fn next_nums(num: u64) -> Vec<u64> {
+        let mut digits = 1;
+
+        while num / 10u64.pow(digits) > 0 {
+            digits += 1
+        }
+
+        if num == 0 {
+            vec![1]
+        } else if digits % 2 == 0 {
+            let d = 10u64.pow(digits / 2);
+            vec![num / d, num % d]
+        } else {
+            vec![num * 2024]
+        }
+    }
+
+    fn blink_num_n_times(num: u64, memo: &mut Memo<(u64, usize), usize>, depth: usize) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+
+        memo.get_or_insert_with((num, depth), |memo| {
+            next_nums(num)
+                .into_iter()
+                .map(|num| blink_num_n_times(num, memo, depth - 1))
+                .sum()
+        })
+    }
+
+    pub fn blink_n_times(nums: &[u64], n: usize) -> usize {
+        let mut memo = Memo::new();
+        nums.iter()
+            .map(|num| blink_num_n_times(*num, &mut memo, n))
+            .sum()
+    }
+
+    /// Blinks one level at a time, keeping how many stones show each number
+    /// rather than the stones themselves. Stones with the same number go on
+    /// the same way, and there are only ever a few thousand different
+    /// numbers, so memory stays small however many times the stones blink.
+    pub fn blink_n_times_counting(nums: &[u64], n: usize) -> usize {
+        let mut counts = HashMap::<u64, usize>::new();
+        for num in nums {
+            *counts.entry(*num).or_default() += 1;
+        }
+
+        for blink in 1..=n {
+            let mut next_counts = HashMap::with_capacity(counts.len());
+            for (num, count) in counts {
+                for next_num in next_nums(num) {
+                    *next_counts.entry(next_num).or_default() += count;
+                }
+            }
+            counts = next_counts;
+            tracing::trace!(blink, distinct_stones = counts.len());
+        }
+
+        counts.values().sum()
+    }
+
+    /// Blinks the row of stones itself.
+    #[cfg(any(test, feature = "reference"))]
+    pub fn blink_n_times_one_by_one(nums: &[u64], n: usize) -> usize {
+        let mut stones = nums.to_vec();
+        for _ in 0..n {
+            stones = stones.into_iter().flat_map(next_nums).collect();
+        }
+        stones.len()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            super::example::output(),
+            blink_n_times(&super::example::intermediate(), 25)
+        );
+        assert_eq!(
+            super::example::output(),
+            blink_n_times_counting(&super::example::intermediate(), 25)
+        );
+    }
+
+    #[test]
+    fn counting_matches_recursion() {
+        let nums = [0, 1, 10, 99, 999, 2024, 125, 17];
+        for n in [0, 1, 6, 30, 75] {
+            assert_eq!(blink_n_times(&nums, n), blink_n_times_counting(&nums, n));
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_matches_one_by_one(nums in crate::strategies::stones(), n in 0usize..15) {
+            let one_by_one = blink_n_times_one_by_one(&nums, n);
+            proptest::prop_assert_eq!(one_by_one, blink_n_times(&nums, n));
+            proptest::prop_assert_eq!(one_by_one, blink_n_times_counting(&nums, n));
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(11), "example")
+    }
+
+    pub fn intermediate() -> Vec<u64> {
+        crate::golden::parse(super::parser::input, input())
+    }
+
+    pub fn output() -> usize {
+        55312
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output());
+
+    #[test]
+    fn params() {
+        let answer = super::solution_with(input(), super::Params { blinks: 25 }).unwrap();
+        assert_eq!(output(), answer.part_2);
+    }
+}
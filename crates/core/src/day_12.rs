@@ -0,0 +1,423 @@
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    diagram::{self, Diagram},
+    error::{parse_complete, ParseError, SolveError},
+    grid::{Direction, Grid, Position},
+    phase,
+    validate::{grid_shape, Report},
+    viz,
+};
+
+pub const TITLE: &str = "Garden Groups";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(size = ?input.size(), "parsed garden");
+
+    let (part_1, part_2) = solution::calculate_total_price(&input);
+
+    Ok(Answer {
+        part_1: part_1.into(),
+        part_2: part_2.into(),
+    })
+}
+
+/// Checks that the grid is rectangular and parses it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    if grid_shape(input, &mut report) {
+        parse(input)?;
+    }
+    Ok(report)
+}
+
+/// A region of plots with the same plant next to each other, and how it's
+/// measured for the fence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub plant: char,
+    /// In the order they were found, starting with the top left one.
+    pub plots: Vec<Position>,
+    pub perimeter: usize,
+    /// Counted as corners, a polygon has as many of them as it has sides.
+    pub sides: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    /// The price of the fence in part 1.
+    pub fn price(&self) -> usize {
+        self.area() * self.perimeter
+    }
+
+    /// The price of the fence with the bulk discount of part 2.
+    pub fn discounted_price(&self) -> usize {
+        self.area() * self.sides
+    }
+}
+
+/// Every region of `garden`, in the order of their top left plots.
+pub fn regions(garden: &Grid<char>) -> Vec<Region> {
+    solution::regions(garden)
+}
+
+/// Shows the garden with every region in a color of its own, followed by the
+/// area, perimeter and sides of each region. The colors need `options.color`,
+/// without it regions of the same plant only tell apart by not touching.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let garden = parse(input)?;
+    let regions = regions(&garden);
+    let region_ids = region_ids(&garden, &regions);
+
+    let map = region_ids
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&id| {
+                    let plant = regions[id].plant;
+                    if options.color {
+                        let style = viz::id_color(id).on_default();
+                        format!("{style}{plant}{style:#}")
+                    } else {
+                        plant.to_string()
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    let report = regions
+        .iter()
+        .map(|region| {
+            let Position {
+                row_index,
+                col_index,
+            } = region.plots[0];
+            format!(
+                "{} at {col_index},{row_index}: area {}, perimeter {}, sides {}",
+                region.plant,
+                region.area(),
+                region.perimeter,
+                region.sides
+            )
+        })
+        .join("\n");
+
+    Ok(sink.frame(&format!("{map}\n\n{report}"))?)
+}
+
+/// The garden as an SVG diagram, with the border of every region around it
+/// and each region in a color of its own.
+pub fn diagram(input: &str) -> Result<String, SolveError> {
+    let garden = phase::parsing(|| parse(input))?;
+    let regions = regions(&garden);
+    let region_ids = region_ids(&garden, &regions);
+
+    let mut diagram = Diagram::new(&region_ids, |id| diagram::Cell {
+        // Golden angle steps keep neighbouring ids apart.
+        fill: format!("hsl({} 60% 75%)", id * 137 % 360),
+        label: Some(regions[*id].plant.to_string()),
+    });
+    for (position, id) in region_ids.iter() {
+        for side in Direction::ALL {
+            let outside = position
+                .checked_add_offset(side.to_offset(), garden.size().into())
+                .is_none_or(|neighbor| region_ids[neighbor] != *id);
+            if outside {
+                diagram.border(position, side, "black");
+            }
+        }
+    }
+    Ok(diagram.to_svg())
+}
+
+/// Which of `regions` each plot belongs to, as its index.
+fn region_ids(garden: &Grid<char>, regions: &[Region]) -> Grid<usize> {
+    let mut region_ids = Grid::fill_with(0, garden.size());
+    for (id, region) in regions.iter().enumerate() {
+        region.plots.iter().for_each(|plot| region_ids[*plot] = id);
+    }
+    region_ids
+}
+
+/// The garden's plots, as the plants growing on them.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    parse_complete(parser::input, input)
+}
+
+pub mod parser {
+    use crate::{
+        grid::Grid,
+        parse_util::{char_grid, Parser},
+    };
+
+    pub fn input(input: &str) -> nom::IResult<&str, Grid<char>> {
+        char_grid(|ch| ch.is_ascii_alphabetic()).parse(input)
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(
+            &mut Rng::new(12),
+            |rng| (b'A' + rng.below(26) as u8) as char,
+            &[],
+        );
+        b.iter(|| input(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use super::Region;
+    use crate::{
+        grid::{Grid, Offset, Position},
+        search::flood_fill,
+    };
+
+    pub fn calculate_total_price(grid: &Grid<char>) -> (usize, usize) {
+        regions(grid)
+            .iter()
+            .fold((0, 0), |(total_price_p_1, total_price_p_2), region| {
+                (
+                    total_price_p_1 + region.price(),
+                    total_price_p_2 + region.discounted_price(),
+                )
+            })
+    }
+
+    pub fn regions(grid: &Grid<char>) -> Vec<Region> {
+        let grid_size = grid.size();
+        let mut visited = Grid::fill_with(false, grid_size);
+        let mut regions = Vec::new();
+
+        for (position, region_identifier) in grid.iter() {
+            if visited[position] {
+                continue;
+            }
+
+            let mut region = Region {
+                plant: *region_identifier,
+                plots: Vec::new(),
+                perimeter: 0,
+                sides: 0,
+            };
+
+            let neighbors_in_region = |position: Position| {
+                grid.neighbor_cells4(position)
+                    .filter(|(_, identifier)| *identifier == region_identifier)
+                    .map(|(position, _)| position)
+            };
+
+            flood_fill(
+                position,
+                |position| neighbors_in_region(*position),
+                |position| {
+                    let number_of_neighbors = neighbors_in_region(*position).count();
+
+                    region.plots.push(*position);
+                    region.perimeter += 4 - number_of_neighbors;
+                    if number_of_neighbors < 4 {
+                        region.sides += number_of_corners(*position, grid);
+                    }
+
+                    visited[*position] = true;
+                },
+            );
+
+            tracing::trace!(
+                %region_identifier,
+                area = region.area(),
+                region.perimeter,
+                region.sides,
+                "measured region"
+            );
+            regions.push(region);
+        }
+        tracing::debug!(regions = regions.len(), "measured every region");
+
+        regions
+    }
+
+    /// Whether the cell at `offset` from `position` belongs to a region other
+    /// than the one of `position`. Only asked about cells next to
+    /// `position`, or diagonal to it next to a cell of its region, so a
+    /// different plant is all it takes.
+    fn is_not_in_current_region(position: Position, offset: Offset, grid: &Grid<char>) -> bool {
+        match position.checked_add_offset(offset, grid.size().into()) {
+            Some(neighbor) => grid[neighbor] != grid[position],
+            None => true,
+        }
+    }
+
+    fn is_corner(
+        edge_position: Position,
+        offset_1: Offset,
+        offset_2: Offset,
+        grid: &Grid<char>,
+    ) -> bool {
+        is_not_in_current_region(edge_position, offset_1, grid)
+            && (is_not_in_current_region(edge_position, offset_2, grid)
+                || !is_not_in_current_region(edge_position, offset_1.unchecked_add(offset_2), grid))
+    }
+
+    fn number_of_corners(edge_position: Position, grid: &Grid<char>) -> usize {
+        [
+            (Offset::LEFT, Offset::UP),
+            (Offset::UP, Offset::RIGHT),
+            (Offset::RIGHT, Offset::DOWN),
+            (Offset::DOWN, Offset::LEFT),
+        ]
+        .into_iter()
+        .filter(|(offset_1, offset_2)| is_corner(edge_position, *offset_1, *offset_2, grid))
+        .count()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            (
+                super::example::output_1_p_1(),
+                super::example::output_1_p_2()
+            ),
+            calculate_total_price(&super::example::intermediate_1())
+        );
+        assert_eq!(
+            (
+                super::example::output_2_p_1(),
+                super::example::output_2_p_2()
+            ),
+            calculate_total_price(&super::example::intermediate_2())
+        );
+        assert_eq!(
+            (
+                super::example::output_3_p_1(),
+                super::example::output_3_p_2()
+            ),
+            calculate_total_price(&super::example::intermediate_3())
+        );
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use crate::grid::Grid;
+
+    pub fn input_1() -> &'static str {
+        examples::text(Day::new(12), "example.1")
+    }
+
+    pub fn input_2() -> &'static str {
+        examples::text(Day::new(12), "example.2")
+    }
+
+    pub fn input_3() -> &'static str {
+        examples::text(Day::new(12), "example.3")
+    }
+
+    pub fn intermediate_1() -> Grid<char> {
+        crate::golden::parse(super::parser::input, input_1())
+    }
+
+    pub fn intermediate_2() -> Grid<char> {
+        crate::golden::parse(super::parser::input, input_2())
+    }
+
+    pub fn intermediate_3() -> Grid<char> {
+        crate::golden::parse(super::parser::input, input_3())
+    }
+
+    pub fn output_1_p_1() -> usize {
+        140
+    }
+
+    pub fn output_2_p_1() -> usize {
+        772
+    }
+
+    pub fn output_3_p_1() -> usize {
+        1930
+    }
+
+    pub fn output_1_p_2() -> usize {
+        80
+    }
+
+    pub fn output_2_p_2() -> usize {
+        436
+    }
+
+    pub fn output_3_p_2() -> usize {
+        1206
+    }
+
+    #[test]
+    fn regions() {
+        let regions = super::regions(&intermediate_1());
+        assert_eq!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ],
+            regions
+                .iter()
+                .map(|region| (region.plant, region.area(), region.perimeter, region.sides))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(crate::grid::Position::new(1, 2), regions[2].plots[0]);
+    }
+
+    #[test]
+    fn visualize() {
+        let mut frames = Vec::<String>::new();
+        let options = crate::viz::Options {
+            part: 1,
+            color: false,
+        };
+        super::visualize(input_1(), &options, &mut frames).unwrap();
+        assert_eq!(
+            vec![format!(
+                "{}\n\n\
+                 A at 0,0: area 4, perimeter 10, sides 4\n\
+                 B at 0,1: area 4, perimeter 8, sides 4\n\
+                 C at 2,1: area 4, perimeter 10, sides 8\n\
+                 D at 3,1: area 1, perimeter 4, sides 4\n\
+                 E at 0,3: area 3, perimeter 8, sides 4",
+                input_1().trim_end()
+            )],
+            frames
+        );
+    }
+
+    #[test]
+    fn diagram() {
+        let svg = super::diagram(input_1()).unwrap();
+        // Every region has as many borders as its perimeter.
+        assert_eq!(10 + 8 + 10 + 4 + 8, svg.matches("<line ").count());
+        assert_eq!(16, svg.matches("<text ").count());
+    }
+
+    crate::golden::golden!(golden_1, input_1(), part_1: output_1_p_1(), part_2: output_1_p_2());
+    crate::golden::golden!(golden_2, input_2(), part_1: output_2_p_1(), part_2: output_2_p_2());
+    crate::golden::golden!(golden_3, input_3(), part_1: output_3_p_1(), part_2: output_3_p_2());
+}
@@ -0,0 +1,684 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Claw Contraption";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(machines = input.len(), "parsed claw machines");
+
+    Ok(Answer {
+        part_1: solution::total_tokens_needed_part_1(&input)?.into(),
+        part_2: solution::total_tokens_needed_part_2(&input)?.into(),
+    })
+}
+
+/// Parses the claw machines and points out the ones whose buttons move the
+/// claw along the same line, which have no single way to win their prize.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let machines = parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!("{} claw machines", machines.len()));
+    for (
+        machine_index,
+        ClawMachine {
+            button_a, button_b, ..
+        },
+    ) in machines.iter().enumerate()
+    {
+        let parallel = button_a
+            .x_offset
+            .checked_mul(button_b.y_offset)
+            .zip(button_a.y_offset.checked_mul(button_b.x_offset))
+            .map(|(l, r)| l == r)
+            .ok_or(NumericOverflow { machine_index })?;
+        if parallel {
+            report.note(format_args!(
+                "machine {machine_index}: buttons A and B move the claw in the same direction"
+            ));
+        }
+    }
+    Ok(report)
+}
+
+/// Part 1 from trying every number of presses up to 100, and part 2 from
+/// Cramer's rule in plain integer arithmetic, which `--self-check` compares
+/// [`solution`] with.
+#[cfg(any(test, feature = "reference"))]
+pub fn reference_solution(input: &str) -> Result<Answer, SolveError> {
+    let input = parse(input)?;
+    Ok(Answer {
+        part_1: solution::reference::total_tokens_by_trying(&input).into(),
+        part_2: solution::reference::total_tokens_by_cramers_rule(&solution::make_part_2_input(
+            &input,
+        )?)
+        .into(),
+    })
+}
+
+/// Solving a claw machine needed intermediate values beyond what `i128` (or
+/// `u128` for token counts) can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("numeric overflow while solving claw machine #{machine_index}")]
+pub struct NumericOverflow {
+    pub machine_index: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ClawMachine {
+    pub button_a: Button,
+    pub button_b: Button,
+    pub prize: Prize,
+}
+
+/// How far a press of the button moves the claw.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Button {
+    pub x_offset: i128,
+    pub y_offset: i128,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Prize {
+    pub x: i128,
+    pub y: i128,
+}
+
+/// The claw machines.
+pub fn parse(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    parse_complete(parser::input, input)
+}
+
+pub mod parser {
+    use super::{Button, ClawMachine, Prize};
+    use crate::parse_util::{blank_lines, labeled, Parser};
+
+    pub fn input(input: &str) -> nom::IResult<&str, Vec<ClawMachine>> {
+        nom::multi::separated_list1(blank_lines(), claw_machine).parse(input)
+    }
+
+    fn claw_machine(input: &str) -> nom::IResult<&str, ClawMachine> {
+        let (input, button_a) = labeled_button('A').parse(input)?;
+        let (input, _) = nom::character::complete::line_ending(input)?;
+        let (input, button_b) = labeled_button('B').parse(input)?;
+        let (input, _) = nom::character::complete::line_ending(input)?;
+        let (input, prize) = prize().parse(input)?;
+        Ok((
+            input,
+            ClawMachine {
+                button_a,
+                button_b,
+                prize,
+            },
+        ))
+    }
+
+    fn prize<'a>() -> impl Parser<'a, Prize> {
+        labeled("Prize: ", coordinates("X=", "Y=")).map(|(x, y)| Prize { x, y })
+    }
+
+    fn labeled_button<'a>(label: char) -> impl Parser<'a, Button> {
+        nom::sequence::preceded(
+            nom::sequence::delimited(
+                nom::bytes::complete::tag("Button "),
+                nom::character::complete::char(label),
+                nom::bytes::complete::tag(": "),
+            ),
+            coordinates("X+", "Y+"),
+        )
+        .map(|(x_offset, y_offset)| Button { x_offset, y_offset })
+    }
+
+    fn coordinates<'a>(
+        x_label: &'static str,
+        y_label: &'static str,
+    ) -> impl Parser<'a, (i128, i128)> {
+        nom::sequence::separated_pair(
+            labeled(x_label, nom::character::complete::i128),
+            nom::bytes::complete::tag(", "),
+            labeled(y_label, nom::character::complete::i128),
+        )
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{Rng, LINES};
+
+        let generated = {
+            let mut rng = Rng::new(13);
+            (0..LINES / 4)
+                .map(|_| {
+                    format!(
+                        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                        rng.between(10, 99),
+                        rng.between(10, 99),
+                        rng.between(10, 99),
+                        rng.between(10, 99),
+                        rng.between(1000, 20000),
+                        rng.between(1000, 20000)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        b.iter(|| input(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use itertools::Itertools;
+
+    use super::{ClawMachine, NumericOverflow};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Overflow;
+
+    /// `n / d` if `d` divides `n`.
+    fn full_div(n: i128, d: i128) -> Result<Option<i128>, Overflow> {
+        if n.checked_rem(d).ok_or(Overflow)? != 0 {
+            return Ok(None);
+        }
+        n.checked_div(d).map(Some).ok_or(Overflow)
+    }
+
+    fn check_and_convert(t: i128, threshold: Option<i128>) -> Option<u128> {
+        (0..=threshold.unwrap_or(i128::MAX))
+            .contains(&t)
+            .then(|| t as u128)
+    }
+
+    fn checked_cross(l: (i128, i128), r: (i128, i128)) -> Result<i128, Overflow> {
+        let (l_0, l_1) = l;
+        let (r_0, r_1) = r;
+        l_0.checked_mul(r_0)
+            .zip(l_1.checked_mul(r_1))
+            .and_then(|(x, y)| x.checked_sub(y))
+            .ok_or(Overflow)
+    }
+
+    fn press_buttons(
+        m: &ClawMachine,
+        threshold: Option<i128>,
+    ) -> Result<Option<(u128, u128)>, Overflow> {
+        let ClawMachine {
+            button_a,
+            button_b,
+            prize,
+        } = m;
+
+        // a = (T_X * Y_B - T_Y * X_B) / (X_A * Y_B - Y_A * X_B)
+        // b = (X_A * T_Y - Y_A * T_X) / (X_A * Y_B - Y_A * X_B)
+        let determinant = checked_cross(
+            (button_a.x_offset, button_a.y_offset),
+            (button_b.y_offset, button_b.x_offset),
+        )?;
+        if determinant == 0 {
+            return press_collinear_buttons(m, threshold);
+        }
+        let a_numerator =
+            checked_cross((prize.x, prize.y), (button_b.y_offset, button_b.x_offset))?;
+        let b_numerator =
+            checked_cross((button_a.x_offset, button_a.y_offset), (prize.y, prize.x))?;
+        let (Some(a), Some(b)) = (
+            full_div(a_numerator, determinant)?,
+            full_div(b_numerator, determinant)?,
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(check_and_convert(a, threshold).zip(check_and_convert(b, threshold)))
+    }
+
+    /// Buttons that move the claw along the same line, if at all. The prize
+    /// has to be on that line too, and then there may be many ways to get to
+    /// it, of which the one with the fewest tokens is picked.
+    fn press_collinear_buttons(
+        m: &ClawMachine,
+        threshold: Option<i128>,
+    ) -> Result<Option<(u128, u128)>, Overflow> {
+        let ClawMachine {
+            button_a,
+            button_b,
+            prize,
+        } = m;
+
+        for button in [button_a, button_b] {
+            if checked_cross((button.x_offset, button.y_offset), (prize.y, prize.x))? != 0 {
+                return Ok(None);
+            }
+        }
+
+        // Along the line, going as far along X (or Y when the line is
+        // upright) as the prize is far is enough to get to it.
+        if (button_a.x_offset, button_b.x_offset) != (0, 0) {
+            presses_along_line(button_a.x_offset, button_b.x_offset, prize.x, threshold)
+        } else if (button_a.y_offset, button_b.y_offset) != (0, 0) {
+            presses_along_line(button_a.y_offset, button_b.y_offset, prize.y, threshold)
+        } else {
+            // Neither button moves the claw.
+            Ok(((prize.x, prize.y) == (0, 0)).then_some((0, 0)))
+        }
+    }
+
+    /// The cheapest `a` and `b` presses with `a * p + b * q == t`, where `p`
+    /// and `q` aren't both zero.
+    fn presses_along_line(
+        p: i128,
+        q: i128,
+        t: i128,
+        threshold: Option<i128>,
+    ) -> Result<Option<(u128, u128)>, Overflow> {
+        let (gcd, x, y) = extended_gcd(p, q)?;
+        let Some(times) = full_div(t, gcd)? else {
+            return Ok(None);
+        };
+
+        // Every solution is a = a_0 + k * a_step and b = b_0 + k * b_step for
+        // some k, of which only a range keeps the presses within bounds.
+        let a = (x.checked_mul(times).ok_or(Overflow)?, q / gcd);
+        let b = (
+            y.checked_mul(times).ok_or(Overflow)?,
+            (p / gcd).checked_neg().ok_or(Overflow)?,
+        );
+        let mut range = Some((None, None));
+        for (start, step) in [a, b] {
+            range = at_least_zero(range, start, step)?;
+            if let Some(threshold) = threshold {
+                let headroom = threshold.checked_sub(start).ok_or(Overflow)?;
+                range = at_least_zero(range, headroom, step.checked_neg().ok_or(Overflow)?)?;
+            }
+        }
+        let Some((lower, upper)) = range else {
+            return Ok(None);
+        };
+
+        // The tokens change by the same amount with every step of k, so the
+        // fewest are at one end of the range. The end is always there, or
+        // the tokens would go below zero the other way.
+        let tokens_per_step =
+            a.1.checked_mul(3)
+                .and_then(|a_tokens| a_tokens.checked_add(b.1))
+                .ok_or(Overflow)?;
+        let k =
+            if tokens_per_step > 0 { lower } else { upper }.expect("the tokens are bounded below");
+        let presses = |(start, step): (i128, i128)| {
+            step.checked_mul(k)
+                .and_then(|offset| start.checked_add(offset))
+                .ok_or(Overflow)
+        };
+        Ok(
+            check_and_convert(presses(a)?, threshold)
+                .zip(check_and_convert(presses(b)?, threshold)),
+        )
+    }
+
+    /// `(gcd, x, y)` with `p * x + q * y == gcd`, the greatest common divisor
+    /// of `p` and `q` up to its sign.
+    fn extended_gcd(p: i128, q: i128) -> Result<(i128, i128, i128), Overflow> {
+        let (mut r, mut next_r) = (p, q);
+        let (mut x, mut next_x) = (1i128, 0i128);
+        let (mut y, mut next_y) = (0i128, 1i128);
+        while next_r != 0 {
+            let quotient = r.checked_div(next_r).ok_or(Overflow)?;
+            let step = |current: i128, next: i128| {
+                quotient
+                    .checked_mul(next)
+                    .and_then(|by| current.checked_sub(by))
+                    .ok_or(Overflow)
+            };
+            (r, next_r) = (next_r, step(r, next_r)?);
+            (x, next_x) = (next_x, step(x, next_x)?);
+            (y, next_y) = (next_y, step(y, next_y)?);
+        }
+        Ok((r, x, y))
+    }
+
+    /// The lower and upper bounds of a range, `None` where it's unbounded.
+    type Range = (Option<i128>, Option<i128>);
+
+    /// Narrows `range` down to the `k`s with `start + k * step >= 0`, `None`
+    /// when there are none left.
+    fn at_least_zero(
+        range: Option<Range>,
+        start: i128,
+        step: i128,
+    ) -> Result<Option<Range>, Overflow> {
+        let Some((mut lower, mut upper)) = range else {
+            return Ok(None);
+        };
+        match step.signum() {
+            0 if start < 0 => return Ok(None),
+            0 => {}
+            1 => {
+                let bound = start.div_euclid(step).checked_neg().ok_or(Overflow)?;
+                lower = Some(lower.map_or(bound, |lower| lower.max(bound)));
+            }
+            _ => {
+                let bound = start.div_euclid(step.checked_neg().ok_or(Overflow)?);
+                upper = Some(upper.map_or(bound, |upper| upper.min(bound)));
+            }
+        }
+        Ok(match (lower, upper) {
+            (Some(lower), Some(upper)) if lower > upper => None,
+            range => Some(range),
+        })
+    }
+
+    fn tokens_needed(m: &ClawMachine, threshold: Option<i128>) -> Result<Option<u128>, Overflow> {
+        let Some((a, b)) = press_buttons(m, threshold)? else {
+            return Ok(None);
+        };
+        a.checked_mul(3)
+            .and_then(|a_tokens| a_tokens.checked_add(b))
+            .map(Some)
+            .ok_or(Overflow)
+    }
+
+    fn total_tokens_needed(
+        ms: &[ClawMachine],
+        threshold: Option<i128>,
+    ) -> Result<u128, NumericOverflow> {
+        ms.iter()
+            .enumerate()
+            .try_fold(0u128, |total, (machine_index, m)| {
+                tokens_needed(m, threshold)
+                    .and_then(|tokens| total.checked_add(tokens.unwrap_or(0)).ok_or(Overflow))
+                    .map_err(|Overflow| NumericOverflow { machine_index })
+            })
+    }
+
+    pub fn total_tokens_needed_part_1(ms: &[ClawMachine]) -> Result<u128, NumericOverflow> {
+        total_tokens_needed(ms, Some(100))
+    }
+
+    pub fn total_tokens_needed_part_2(ms: &[ClawMachine]) -> Result<u128, NumericOverflow> {
+        let ms = make_part_2_input(ms)?;
+        total_tokens_needed(&ms, None)
+    }
+
+    pub fn make_part_2_input(input: &[ClawMachine]) -> Result<Vec<ClawMachine>, NumericOverflow> {
+        const PRIZE_OFFSET: i128 = 10000000000000;
+
+        input
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(machine_index, mut m)| {
+                m.prize.x = m
+                    .prize
+                    .x
+                    .checked_add(PRIZE_OFFSET)
+                    .ok_or(NumericOverflow { machine_index })?;
+                m.prize.y = m
+                    .prize
+                    .y
+                    .checked_add(PRIZE_OFFSET)
+                    .ok_or(NumericOverflow { machine_index })?;
+                Ok(m)
+            })
+            .try_collect()
+    }
+
+    #[cfg(any(test, feature = "reference"))]
+    pub mod reference {
+        use super::ClawMachine;
+
+        pub fn tokens_by_trying(m: &ClawMachine) -> Option<u128> {
+            (0..=100i128)
+                .flat_map(|a| (0..=100i128).map(move |b| (a, b)))
+                .filter(|(a, b)| {
+                    a * m.button_a.x_offset + b * m.button_b.x_offset == m.prize.x
+                        && a * m.button_a.y_offset + b * m.button_b.y_offset == m.prize.y
+                })
+                .map(|(a, b)| 3 * a as u128 + b as u128)
+                .min()
+        }
+
+        /// Doesn't know what to do with parallel buttons, but the puzzle
+        /// inputs don't have any.
+        fn tokens_by_cramers_rule(m: &ClawMachine) -> Option<u128> {
+            let (a, b, prize) = (m.button_a, m.button_b, m.prize);
+            let determinant = a.x_offset * b.y_offset - a.y_offset * b.x_offset;
+            let a_presses = prize.x * b.y_offset - prize.y * b.x_offset;
+            let b_presses = a.x_offset * prize.y - a.y_offset * prize.x;
+            if determinant == 0 || a_presses % determinant != 0 || b_presses % determinant != 0 {
+                return None;
+            }
+            let (a_presses, b_presses) = (a_presses / determinant, b_presses / determinant);
+            (a_presses >= 0 && b_presses >= 0).then(|| 3 * a_presses as u128 + b_presses as u128)
+        }
+
+        pub fn total_tokens_by_trying(ms: &[ClawMachine]) -> u128 {
+            ms.iter().filter_map(tokens_by_trying).sum()
+        }
+
+        pub fn total_tokens_by_cramers_rule(ms: &[ClawMachine]) -> u128 {
+            ms.iter().filter_map(tokens_by_cramers_rule).sum()
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            Ok(super::example::output_p_1()),
+            total_tokens_needed_part_1(&super::example::intermediate())
+        );
+
+        assert_eq!(
+            Ok(super::example::output_p_2()),
+            total_tokens_needed_part_2(&super::example::intermediate())
+        );
+    }
+
+    #[cfg(test)]
+    fn machine(button_a: (i128, i128), button_b: (i128, i128), prize: (i128, i128)) -> ClawMachine {
+        use super::{Button, Prize};
+
+        ClawMachine {
+            button_a: Button {
+                x_offset: button_a.0,
+                y_offset: button_a.1,
+            },
+            button_b: Button {
+                x_offset: button_b.0,
+                y_offset: button_b.1,
+            },
+            prize: Prize {
+                x: prize.0,
+                y: prize.1,
+            },
+        }
+    }
+
+    #[test]
+    fn i128_boundary() {
+        assert_eq!(Ok(Some(-3)), full_div(6, -2));
+        assert_eq!(Ok(None), full_div(7, 2));
+        assert_eq!(Err(Overflow), full_div(i128::MIN, -1));
+
+        let big = 1i128 << 62;
+        assert_eq!(
+            Ok(Some((1, 1))),
+            press_buttons(&machine((big, 1), (1, big), (big + 1, big + 1)), None)
+        );
+
+        assert_eq!(
+            Err(Overflow),
+            press_buttons(
+                &machine((i128::MAX, 1), (1, i128::MAX), (i128::MAX, i128::MAX)),
+                None
+            )
+        );
+        assert_eq!(
+            Err(NumericOverflow { machine_index: 1 }),
+            total_tokens_needed(
+                &[
+                    machine((big, 1), (1, big), (big + 1, big + 1)),
+                    machine((i128::MIN, 1), (1, 2), (3, 4)),
+                ],
+                None
+            )
+        );
+
+        assert_eq!(
+            Err(NumericOverflow { machine_index: 0 }),
+            make_part_2_input(&[machine((1, 0), (0, 1), (i128::MAX - 1, 0))])
+        );
+    }
+
+    #[test]
+    fn collinear_buttons() {
+        // Four presses of B get as far as one of A, for a token more.
+        assert_eq!(
+            Ok(Some((2, 2))),
+            press_buttons(&machine((4, 4), (1, 1), (10, 10)), None)
+        );
+        // Two presses of B get as far as one of A, for a token less.
+        assert_eq!(
+            Ok(Some((0, 5))),
+            press_buttons(&machine((2, 2), (1, 1), (5, 5)), None)
+        );
+        assert_eq!(
+            Ok(Some((50, 100))),
+            press_buttons(&machine((1, 1), (1, 1), (150, 150)), Some(100))
+        );
+        assert_eq!(
+            Ok(Some((1, 2))),
+            press_buttons(&machine((0, 5), (0, 1), (0, 7)), None)
+        );
+        assert_eq!(
+            Ok(Some((1, 1))),
+            press_buttons(&machine((3, 3), (-1, -1), (2, 2)), None)
+        );
+
+        // Off the line, or between the stops along it.
+        assert_eq!(
+            Ok(None),
+            press_buttons(&machine((1, 2), (2, 4), (3, 5)), None)
+        );
+        assert_eq!(
+            Ok(None),
+            press_buttons(&machine((2, 2), (4, 4), (3, 3)), None)
+        );
+        assert_eq!(
+            Ok(None),
+            press_buttons(&machine((2, 2), (4, 4), (-2, -2)), None)
+        );
+
+        assert_eq!(
+            Ok(Some((0, 0))),
+            press_buttons(&machine((0, 0), (0, 0), (0, 0)), None)
+        );
+        assert_eq!(
+            Ok(None),
+            press_buttons(&machine((0, 0), (0, 0), (1, 0)), None)
+        );
+        assert_eq!(
+            Ok(Some((0, 3))),
+            press_buttons(&machine((0, 0), (1, 2), (3, 6)), None)
+        );
+
+        for (a, b, prize) in
+            itertools::iproduct!(0..5, 0..5, (0..40).map(|t| (t, 2 * t)).chain([(3, 7)]))
+        {
+            let m = machine((a, 2 * a), (b, 2 * b), prize);
+            assert_eq!(
+                Ok(reference::tokens_by_trying(&m)),
+                tokens_needed(&m, Some(100)),
+                "{m:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn validating() {
+        use crate::error::SolveError;
+
+        let report = super::validate(
+            "Button A: X+1, Y+2\nButton B: X+2, Y+4\nPrize: X=3, Y=6\n\n\
+             Button A: X+1, Y+2\nButton B: X+2, Y+1\nPrize: X=3, Y=3",
+        )
+        .unwrap();
+        assert!(report.is_valid());
+        assert_eq!(2, report.notes.len());
+
+        assert_eq!(
+            Err(SolveError::NumericOverflow(NumericOverflow {
+                machine_index: 0
+            })),
+            super::validate(&format!(
+                "Button A: X+{0}, Y+1\nButton B: X+1, Y+{0}\nPrize: X=1, Y=1",
+                i128::MAX
+            ))
+            .map(drop)
+        );
+    }
+
+    #[test]
+    fn button_a_not_moving_along_x() {
+        assert_eq!(
+            Ok(Some((3, 2))),
+            press_buttons(&machine((0, 1), (1, 1), (2, 5)), None)
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_finds_the_presses((input, tokens) in crate::strategies::winnable_claw_machine()) {
+            proptest::prop_assert_eq!(tokens, super::solution(&input).unwrap().part_1);
+        }
+
+        #[test]
+        fn prop_at_most_100_presses_each(input in crate::strategies::claw_machines()) {
+            let machines = input.split("\n\n").count() as u128;
+            let answer = super::solution(&input).unwrap();
+            proptest::prop_assert!(answer.part_1 <= (machines * (3 * 100 + 100)).into());
+        }
+
+        #[test]
+        fn prop_matches_reference(input in crate::strategies::claw_machines()) {
+            let answer = super::solution(&input).unwrap();
+            let reference = super::reference_solution(&input).unwrap();
+            proptest::prop_assert_eq!(
+                (reference.part_1, reference.part_2),
+                (answer.part_1, answer.part_2)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::ClawMachine;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(13), "example")
+    }
+
+    pub fn intermediate() -> Vec<ClawMachine> {
+        crate::golden::parse(super::parser::input, input())
+    }
+
+    pub fn output_p_1() -> u128 {
+        480
+    }
+
+    pub fn output_p_2() -> u128 {
+        875318608908
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
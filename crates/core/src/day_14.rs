@@ -0,0 +1,399 @@
+use anstyle::AnsiColor;
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    grid::{Grid, GridSize, Offset, Position},
+    phase,
+    validate::Report,
+    viz,
+};
+
+pub const TITLE: &str = "Restroom Redoubt";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+}
+
+/// Works out the size of the space from where the robots are, see
+/// [`space_of`].
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    solution_with(input, Params::default())
+}
+
+/// What the puzzle text says rather than the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Worked out from where the robots are if `None`.
+    pub space: Option<GridSize>,
+    /// How long the robots move before the safety factor is taken.
+    pub seconds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            space: None,
+            seconds: 100,
+        }
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution_with(input: &str, params: Params) -> Result<Answer, SolveError> {
+    let robots = phase::parsing(|| parse(input))?;
+    let space = params.space.unwrap_or_else(|| space_of(&robots));
+    tracing::debug!(robots = robots.len(), ?space, "parsed robots");
+
+    Ok(Answer {
+        part_1: solution::calculate_safety_factors(&robots, space, params.seconds).into(),
+    })
+}
+
+/// Parses the robots and checks that they all start out within the space.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let robots = parse(input)?;
+    let mut report = Report::default();
+    let space @ GridSize(rows, cols) = space_of(&robots);
+    report.note(format_args!(
+        "{} robots in a {cols}x{rows} space{}",
+        robots.len(),
+        if space == EXAMPLE_SPACE {
+            ", as in the example"
+        } else {
+            ""
+        }
+    ));
+    for (robot_idx, robot) in robots.iter().enumerate() {
+        let Position {
+            row_index,
+            col_index,
+        } = robot.current_position;
+        if row_index >= rows || col_index >= cols {
+            report.problem(format_args!(
+                "robot {robot_idx} at {col_index},{row_index} is outside of the {cols}x{rows} space"
+            ));
+        }
+    }
+    Ok(report)
+}
+
+/// Shows where the robots are in the puzzle's notation, the number of robots
+/// on a tile or `.` if there are none. Part 1 shows each of the 100 seconds,
+/// part 2 only the first second in which no two robots share a tile, which is
+/// when they draw the Christmas tree.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let mut robots = parse(input)?;
+    let space @ GridSize(rows, cols) = space_of(&robots);
+
+    if options.part == 1 {
+        for second in 0..=Params::default().seconds {
+            sink.frame(&render_robots(&robots, space, second, options.color))?;
+            robots
+                .iter_mut()
+                .for_each(|robot| *robot = robot.advance(space));
+        }
+        return Ok(());
+    }
+
+    // Every robot is back where it started after `rows * cols` seconds.
+    for second in 0..rows * cols {
+        if robots
+            .iter()
+            .map(|robot| robot.current_position)
+            .all_unique()
+        {
+            return Ok(sink.frame(&render_robots(&robots, space, second, options.color))?);
+        }
+        robots
+            .iter_mut()
+            .for_each(|robot| *robot = robot.advance(space));
+    }
+    Err(SolveError::RobotsOverlap.into())
+}
+
+fn render_robots(robots: &[Robot], space: GridSize, second: usize, color: bool) -> String {
+    let mut counts = Grid::fill_with(0u32, space);
+    robots
+        .iter()
+        .for_each(|robot| counts[robot.current_position] += 1);
+    let grid = counts.render(|count| match count {
+        0 => '.',
+        count => char::from_digit((*count).min(9), 10).unwrap(),
+    });
+    let grid = if color {
+        let style = AnsiColor::Green.on_default();
+        grid.chars()
+            .map(|tile| match tile {
+                '.' | '\n' => tile.to_string(),
+                robot => format!("{style}{robot}{style:#}"),
+            })
+            .collect()
+    } else {
+        grid
+    };
+    format!("After {second} seconds:\n{grid}")
+}
+
+/// Where the robots move around, 101 tiles wide and 103 tall.
+pub const SPACE: GridSize = GridSize(103, 101);
+
+/// Where the robots of the example move around, 11 tiles wide and 7 tall.
+pub const EXAMPLE_SPACE: GridSize = GridSize(7, 11);
+
+/// The puzzle doesn't say how large the space is in the input, only in the
+/// text, so the robots are taken to be in the example's unless some of them
+/// are outside of it.
+fn space_of(robots: &[Robot]) -> GridSize {
+    let GridSize(rows, cols) = EXAMPLE_SPACE;
+    let in_example = robots.iter().all(|robot| {
+        robot.current_position.row_index < rows && robot.current_position.col_index < cols
+    });
+    if in_example {
+        EXAMPLE_SPACE
+    } else {
+        SPACE
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Robot {
+    pub current_position: Position,
+    /// Tiles per second.
+    pub velocity: Offset,
+}
+
+/// The robots.
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_complete(parser::input, input)
+}
+
+pub mod parser {
+    use super::{Offset, Position, Robot};
+    use crate::parse_util::{labeled, lines, Parser};
+
+    pub fn input(input: &str) -> nom::IResult<&str, Vec<Robot>> {
+        lines(robot).parse(input)
+    }
+
+    fn robot(input: &str) -> nom::IResult<&str, Robot> {
+        nom::sequence::separated_pair(position, nom::character::complete::space1, velocity)
+            .map(|(current_position, velocity)| Robot {
+                current_position,
+                velocity,
+            })
+            .parse(input)
+    }
+
+    fn tagged_pair<'a, T>(
+        tag: &'static str,
+        p: impl Copy + Parser<'a, T>,
+    ) -> impl Parser<'a, (T, T)> {
+        labeled(
+            tag,
+            nom::sequence::separated_pair(p, nom::character::complete::char(','), p),
+        )
+    }
+
+    // The puzzle gives `x,y`, i.e. the column before the row.
+    fn position(input: &str) -> nom::IResult<&str, Position> {
+        tagged_pair("p=", nom::character::complete::u64)
+            .map(|(x, y)| Position::new(usize::try_from(y).unwrap(), usize::try_from(x).unwrap()))
+            .parse(input)
+    }
+
+    fn velocity(input: &str) -> nom::IResult<&str, Offset> {
+        tagged_pair("v=", nom::character::complete::i64)
+            .map(|(x, y)| Offset::new(isize::try_from(y).unwrap(), isize::try_from(x).unwrap()))
+            .parse(input)
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(14), |rng| {
+            format!(
+                "p={},{} v={},{}",
+                rng.between(0, 100),
+                rng.between(0, 102),
+                rng.between(-99, 99),
+                rng.between(-99, 99)
+            )
+        });
+        b.iter(|| input(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use super::Robot;
+    use crate::grid::{GridSize, Position};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Quadrant {
+        UL,
+        UR,
+        DL,
+        DR,
+    }
+
+    trait Quadrants {
+        fn quadrant(&self, grid_size: GridSize) -> Option<Quadrant>;
+    }
+
+    impl Quadrants for Position {
+        fn quadrant(&self, grid_size: GridSize) -> Option<Quadrant> {
+            let GridSize(rows, cols) = grid_size;
+            let mid_row = rows / 2;
+            let mid_col = cols / 2;
+
+            let is_l = self.col_index < mid_col;
+            let is_r = self.col_index > mid_col;
+
+            let is_u = self.row_index < mid_row;
+            let is_d = self.row_index > mid_row;
+
+            match (is_u, is_d, is_l, is_r) {
+                (true, false, true, false) => Some(Quadrant::UL),
+                (true, false, false, true) => Some(Quadrant::UR),
+                (false, true, true, false) => Some(Quadrant::DL),
+                (false, true, false, true) => Some(Quadrant::DR),
+                _ => None,
+            }
+        }
+    }
+
+    impl Robot {
+        pub(super) fn advance(&self, grid_size: GridSize) -> Self {
+            Robot {
+                current_position: self
+                    .current_position
+                    .wrapping_add_offset(self.velocity, grid_size),
+                velocity: self.velocity,
+            }
+        }
+    }
+
+    pub fn calculate_safety_factors(robots: &[Robot], grid_size: GridSize, secs: usize) -> u64 {
+        robots
+            .iter()
+            .cloned()
+            .filter_map(|robot| {
+                (0..secs)
+                    .into_iter()
+                    .fold(robot, |robot, _| robot.advance(grid_size))
+                    .current_position
+                    .quadrant(grid_size)
+            })
+            .fold([0u64, 0, 0, 0], |mut counts: [u64; 4], q| {
+                match q {
+                    Quadrant::UL => counts[0] += 1,
+                    Quadrant::UR => counts[1] += 1,
+                    Quadrant::DL => counts[2] += 1,
+                    Quadrant::DR => counts[3] += 1,
+                };
+
+                counts
+            })
+            .into_iter()
+            .product()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            super::example::output(),
+            calculate_safety_factors(&super::example::intermediate(), super::EXAMPLE_SPACE, 100)
+        );
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::Robot;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(14), "example")
+    }
+
+    pub fn intermediate() -> Vec<Robot> {
+        crate::golden::parse(super::parser::input, input())
+    }
+
+    pub fn output() -> u64 {
+        12
+    }
+
+    #[test]
+    fn params() {
+        let solve = |params| super::solution_with(input(), params).unwrap().part_1;
+        assert_eq!(output(), solve(super::Params::default()));
+        assert_eq!(
+            output(),
+            solve(super::Params {
+                space: Some(super::EXAMPLE_SPACE),
+                ..Default::default()
+            })
+        );
+        // Nothing has moved yet, one of the robots is on the middle row.
+        assert_eq!(
+            0u64,
+            solve(super::Params {
+                seconds: 0,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn space() {
+        assert_eq!(super::EXAMPLE_SPACE, super::space_of(&intermediate()));
+
+        let mut robots = intermediate();
+        robots[0].current_position.col_index = 11;
+        assert_eq!(super::SPACE, super::space_of(&robots));
+    }
+
+    #[test]
+    fn visualize() {
+        let mut frames = Vec::<String>::new();
+        let options = crate::viz::Options {
+            part: 1,
+            color: false,
+        };
+        super::visualize(input(), &options, &mut frames).unwrap();
+        assert_eq!(101, frames.len());
+        assert_eq!(
+            "After 0 seconds:\n\
+             1.12.......\n\
+             ...........\n\
+             ...........\n\
+             ......11.11\n\
+             1.1........\n\
+             .........1.\n\
+             .......1...",
+            frames[0]
+        );
+        assert_eq!(
+            "After 100 seconds:\n\
+             ......2..1.\n\
+             ...........\n\
+             1..........\n\
+             .11........\n\
+             .....1.....\n\
+             ...12......\n\
+             .1....1....",
+            frames[100]
+        );
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output());
+}
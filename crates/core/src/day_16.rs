@@ -0,0 +1,404 @@
+use anstyle::AnsiColor;
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    diagram::{self, Diagram},
+    error::{parse_complete, ParseError, SolveError},
+    grid::{Grid, Position},
+    phase,
+    validate::{exactly_one, grid_shape, Report},
+    viz,
+};
+
+pub const TITLE: &str = "Reindeer Maze";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(size = ?input.grid.size(), "parsed maze");
+
+    Ok(Answer {
+        part_1: solution::calaculate_lowest_score(&input)
+            .ok_or(SolveError::Unreachable)?
+            .into(),
+    })
+}
+
+/// Checks that the maze is rectangular with a single start and end, and
+/// parses it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    let rectangular = grid_shape(input, &mut report);
+    exactly_one(input, "start", |ch| ch == 'S', &mut report);
+    exactly_one(input, "end", |ch| ch == 'E', &mut report);
+    if rectangular && report.is_valid() {
+        parse(input)?;
+    }
+    Ok(report)
+}
+
+/// How the lowest score is made up of steps and turns.
+pub fn explain(input: &str) -> Result<String, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+
+    Ok(solution::lowest_score_path(&input)
+        .ok_or(SolveError::Unreachable)?
+        .to_string())
+}
+
+/// Shows the maze with a path of the lowest score on it, drawn as the way the
+/// reindeer faces on every tile.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let input = parse(input)?;
+    let route = solution::lowest_score_route(&input).ok_or(SolveError::Unreachable)?;
+
+    let mut tiles = input.grid.clone().map(|cell| match cell {
+        Cell::Air => '.',
+        Cell::Wall => '#',
+    });
+    for (position, direction) in route {
+        tiles[position] = direction.to_arrow();
+    }
+    tiles[input.starting_position] = 'S';
+    tiles[input.ending_position] = 'E';
+
+    let style = AnsiColor::Yellow.on_default().bold();
+    let frame = tiles
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|&tile| match tile {
+                    '.' | '#' => tile.to_string(),
+                    _ if options.color => format!("{style}{tile}{style:#}"),
+                    _ => tile.to_string(),
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    Ok(sink.frame(&frame)?)
+}
+
+/// The maze as an SVG diagram, with a path of the lowest score on it.
+pub fn diagram(input: &str) -> Result<String, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    let route = solution::lowest_score_route(&input).ok_or(SolveError::Unreachable)?;
+
+    let mut diagram = Diagram::new(&input.grid, |cell| diagram::Cell {
+        fill: match cell {
+            Cell::Air => "#eeeeee",
+            Cell::Wall => "#555555",
+        }
+        .to_owned(),
+        label: None,
+    });
+    diagram
+        .label(input.starting_position, "S")
+        .label(input.ending_position, "E")
+        .path(
+            &route.iter().map(|(position, _)| *position).collect_vec(),
+            "#e6a100",
+        );
+    Ok(diagram.to_svg())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub starting_position: Position,
+    pub ending_position: Position,
+    /// Where the start and end are is air.
+    pub grid: Grid<Cell>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Air,
+    Wall,
+}
+
+/// The maze.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_complete(parser::input, input)
+}
+
+pub mod parser {
+    use itertools::Itertools;
+    use nom::Parser;
+
+    use crate::grid::{Grid, Position};
+
+    use super::{Cell, Input};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum IntermediateCell {
+        Start,
+        End,
+        Wall,
+        Air,
+    }
+
+    fn find_position<F: Fn(&IntermediateCell) -> bool>(
+        tag: &str,
+        f: F,
+        grid: &Grid<IntermediateCell>,
+    ) -> Result<Position, String> {
+        grid.iter()
+            .filter_map(|(position, cell)| f(cell).then_some(position))
+            .exactly_one()
+            .map_err(|err| format!("expect exactly one {tag} position found, err: {err}"))
+    }
+
+    impl TryFrom<Grid<IntermediateCell>> for Input {
+        type Error = String;
+
+        fn try_from(grid: Grid<IntermediateCell>) -> Result<Self, Self::Error> {
+            let starting_position =
+                find_position("starting", |cell| *cell == IntermediateCell::Start, &grid)?;
+            let ending_position =
+                find_position("ending", |cell| *cell == IntermediateCell::End, &grid)?;
+            let grid = grid.map(|cell| match cell {
+                IntermediateCell::Wall => Cell::Wall,
+                _ => Cell::Air,
+            });
+
+            Ok(Input {
+                starting_position,
+                ending_position,
+                grid,
+            })
+        }
+    }
+
+    pub fn input(input: &str) -> nom::IResult<&str, Input> {
+        nom::combinator::map_res(Grid::parse(cell), Input::try_from).parse(input)
+    }
+
+    fn cell(input: &str) -> nom::IResult<&str, IntermediateCell> {
+        nom::character::complete::one_of("SE#.")
+            .map(|value| match value {
+                'S' => IntermediateCell::Start,
+                'E' => IntermediateCell::End,
+                '#' => IntermediateCell::Wall,
+                '.' => IntermediateCell::Air,
+                _ => panic!(),
+            })
+            .parse(input)
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(
+            &mut Rng::new(16),
+            |rng| if rng.below(3) == 0 { '#' } else { '.' },
+            &['S', 'E'],
+        );
+        b.iter(|| input(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::fmt::Display;
+
+    use crate::{
+        grid::{Direction, Position},
+        pathfinding::dijkstra,
+    };
+
+    use super::{Cell, Input};
+
+    const STEP_SCORE: u64 = 1;
+    const TURN_SCORE: u64 = 1000;
+
+    /// The moves along a path with the lowest score.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PathBreakdown {
+        pub steps: u64,
+        pub turns: u64,
+    }
+
+    impl PathBreakdown {
+        pub fn step_score(&self) -> u64 {
+            self.steps * STEP_SCORE
+        }
+
+        pub fn turn_score(&self) -> u64 {
+            self.turns * TURN_SCORE
+        }
+
+        pub fn score(&self) -> u64 {
+            self.step_score() + self.turn_score()
+        }
+    }
+
+    impl Display for PathBreakdown {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{} steps and {} turns: {} + {} = {}",
+                self.steps,
+                self.turns,
+                self.step_score(),
+                self.turn_score(),
+                self.score()
+            )
+        }
+    }
+
+    pub fn calaculate_lowest_score(input: &Input) -> Option<u64> {
+        lowest_score_path(input).map(|breakdown| breakdown.score())
+    }
+
+    pub fn lowest_score_path(input: &Input) -> Option<PathBreakdown> {
+        lowest_score_route(input).map(|path| PathBreakdown {
+            steps: path.len() as u64 - 1,
+            turns: path
+                .iter()
+                .zip(path.iter().skip(1))
+                .map(|((_, current_direction), (_, next_direction))| {
+                    current_direction.turns_to(*next_direction) as u64
+                })
+                .sum(),
+        })
+    }
+
+    /// The tiles along a path with the lowest score, from the start to the
+    /// end, and which way the reindeer faces on each of them.
+    pub fn lowest_score_route(input: &Input) -> Option<Vec<(Position, Direction)>> {
+        let grid_size = input.grid.size();
+
+        // The reindeer starts facing East.
+        dijkstra(
+            [(input.starting_position, Direction::Right)],
+            |&(position, current_direction)| {
+                Direction::ALL.into_iter().filter_map(move |direction| {
+                    let next_position =
+                        position.checked_add_offset(direction.to_offset(), grid_size.into())?;
+                    (input.grid[next_position] == Cell::Air).then(|| {
+                        (
+                            (next_position, direction),
+                            STEP_SCORE + TURN_SCORE * current_direction.turns_to(direction) as u64,
+                        )
+                    })
+                })
+            },
+            |(position, _)| *position == input.ending_position,
+        )
+        .map(|(_, path)| path)
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            Some(super::example::output_1()),
+            calaculate_lowest_score(&super::example::intermediate_1())
+        );
+        assert_eq!(
+            Some(super::example::output_2()),
+            calaculate_lowest_score(&super::example::intermediate_2())
+        );
+    }
+
+    #[test]
+    fn breakdown() {
+        let breakdown = lowest_score_path(&super::example::intermediate_1()).unwrap();
+        assert_eq!(
+            PathBreakdown {
+                steps: 36,
+                turns: 7
+            },
+            breakdown
+        );
+        assert_eq!(super::example::output_1(), breakdown.score());
+        assert_eq!(
+            "36 steps and 7 turns: 36 + 7000 = 7036",
+            breakdown.to_string()
+        );
+
+        assert_eq!(
+            Some(PathBreakdown {
+                steps: 48,
+                turns: 11
+            }),
+            lowest_score_path(&super::example::intermediate_2())
+        );
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::Input;
+
+    pub fn input_1() -> &'static str {
+        examples::text(Day::new(16), "example.1")
+    }
+
+    pub fn input_2() -> &'static str {
+        examples::text(Day::new(16), "example.2")
+    }
+
+    pub fn intermediate_1() -> Input {
+        crate::golden::parse(super::parser::input, input_1())
+    }
+
+    pub fn intermediate_2() -> Input {
+        crate::golden::parse(super::parser::input, input_2())
+    }
+
+    pub fn output_1() -> u64 {
+        7036
+    }
+
+    pub fn output_2() -> u64 {
+        11048
+    }
+
+    #[test]
+    fn visualize() {
+        let mut frames = Vec::<String>::new();
+        let options = crate::viz::Options {
+            part: 1,
+            color: false,
+        };
+        super::visualize(input_1(), &options, &mut frames).unwrap();
+        let [frame] = frames.as_slice() else {
+            panic!("expected a single frame, got {}", frames.len());
+        };
+        assert_eq!(
+            input_1().trim_end().replace(['S', 'E'], "."),
+            frame.replace(['^', '>', 'v', '<', 'S', 'E'], ".")
+        );
+        assert_eq!(
+            36,
+            frame.chars().filter(|tile| "^>v<E".contains(*tile)).count()
+        );
+    }
+
+    #[test]
+    fn diagram() {
+        let svg = super::diagram(input_1()).unwrap();
+        let points = svg
+            .split(r#"points=""#)
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        // One point for every tile from the start to the end.
+        assert_eq!(37, points.split(' ').count());
+        assert!(svg.contains(">S</text>") && svg.contains(">E</text>"));
+    }
+
+    crate::golden::golden!(golden_1, input_1(), part_1: output_1());
+    crate::golden::golden!(golden_2, input_2(), part_1: output_2());
+}
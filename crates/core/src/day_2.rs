@@ -0,0 +1,269 @@
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    incremental::Incremental,
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Red-Nosed Reports";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let reports = phase::parsing(|| parse(input))?;
+    tracing::debug!(reports = reports.len(), "parsed reports");
+    Ok(Answer {
+        part_1: solution::number_of_safe_reports_p1(&reports).into(),
+        part_2: solution::number_of_safe_reports_p2(&reports).into(),
+    })
+}
+
+/// Parses the reports and notes how long they are.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let reports = parse(input)?;
+    let mut report = Report::default();
+    let (shortest, longest) = reports
+        .iter()
+        .map(Vec::len)
+        .minmax()
+        .into_option()
+        .unwrap_or_default();
+    report.note(format_args!(
+        "{} reports of {shortest} to {longest} levels",
+        reports.len()
+    ));
+    Ok(report)
+}
+
+/// The answers worked out the way the puzzle puts it, dropping each level
+/// from a copy of the report in turn. Slow, but hard to get wrong, which is
+/// what `--self-check` compares [`solution`] with.
+#[cfg(any(test, feature = "reference"))]
+pub fn reference_solution(input: &str) -> Result<Answer, SolveError> {
+    let reports = parse(input)?;
+    Ok(Answer {
+        part_1: solution::reference::number_of_safe_reports(&reports, false).into(),
+        part_2: solution::reference::number_of_safe_reports(&reports, true).into(),
+    })
+}
+
+pub fn incremental_solution(
+    state: &mut Incremental<Vec<i64>>,
+    input: &str,
+) -> Result<Answer, SolveError> {
+    let reports = state.update(input, |line| parse_complete(parser::line(), line))?;
+    Ok(Answer {
+        part_1: solution::number_of_safe_reports_p1(reports).into(),
+        part_2: solution::number_of_safe_reports_p2(reports).into(),
+    })
+}
+
+/// The reports, each a list of levels.
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::parse_util::{lines, spaced, Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, Vec<Vec<i64>>> {
+        lines(line::<'a>())
+    }
+
+    pub fn line<'a>() -> impl Parser<'a, Vec<i64>> {
+        spaced(nom::character::complete::i64)
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(2), |rng| {
+            (0..rng.between(5, 8))
+                .map(|_| rng.between(1, 99).to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::mem::replace;
+
+    use itertools::Itertools;
+
+    /// Whether the levels all go up or all go down, by 1 to 3 at a time.
+    fn are_levels_safe<'a>(levels: impl Iterator<Item = &'a i64>) -> bool {
+        let mut direction = 0;
+        levels.tuple_windows().all(|(l, r)| {
+            let diff = r - l;
+            (1..=3).contains(&diff.abs())
+                && replace(&mut direction, diff.signum()) != -diff.signum()
+        })
+    }
+
+    fn is_safe_1(report: &Vec<i64>) -> bool {
+        are_levels_safe(report.iter())
+    }
+
+    /// Tries leaving out every level in turn, skipping it while iterating
+    /// instead of copying the report without it.
+    fn is_safe_2(report: &Vec<i64>) -> bool {
+        (0..report.len()).any(|skipped| {
+            are_levels_safe(
+                report
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| *idx != skipped)
+                    .map(|(_, level)| level),
+            )
+        })
+    }
+
+    pub fn number_of_safe_reports_p1(reports: &[Vec<i64>]) -> usize {
+        reports.iter().filter(|report| is_safe_1(*report)).count()
+    }
+
+    pub fn number_of_safe_reports_p2(reports: &[Vec<i64>]) -> usize {
+        reports.iter().filter(|report| is_safe_2(*report)).count()
+    }
+
+    #[cfg(any(test, feature = "reference"))]
+    pub mod reference {
+        use itertools::Itertools;
+
+        /// Sorted one way or the other, with neighbours 1 to 3 apart.
+        pub fn is_safe(report: &[i64]) -> bool {
+            (report.is_sorted() || report.iter().rev().is_sorted())
+                && report
+                    .iter()
+                    .tuple_windows()
+                    .all(|(l, r)| (1..=3).contains(&(l - r).abs()))
+        }
+
+        pub fn is_safe_with_dampener(report: &[i64]) -> bool {
+            is_safe(report)
+                || (0..report.len()).any(|idx| {
+                    let mut report = report.to_vec();
+                    report.remove(idx);
+                    is_safe(&report)
+                })
+        }
+
+        pub fn number_of_safe_reports(reports: &[Vec<i64>], dampener: bool) -> usize {
+            reports
+                .iter()
+                .filter(|report| match dampener {
+                    false => is_safe(report),
+                    true => is_safe_with_dampener(report),
+                })
+                .count()
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            number_of_safe_reports_p1(&super::example::intermediate()),
+            super::example::output_number_of_safe_reports_p_1()
+        );
+        assert_eq!(
+            number_of_safe_reports_p2(&super::example::intermediate()),
+            super::example::output_number_of_safe_reports_p_2()
+        );
+    }
+
+    #[test]
+    fn edge_cases_p2() {
+        assert!(is_safe_2(&vec![11, 9, 6, 2, 5]));
+        assert!(is_safe_2(&vec![86, 86, 89, 91, 94, 96, 98]));
+        assert!(is_safe_2(&vec![41, 45, 48, 50, 52, 55, 58]));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_is_safe_2_matches_removing(
+            report in proptest::collection::vec(1i64..10, 1..8)
+        ) {
+            proptest::prop_assert_eq!(
+                reference::is_safe_with_dampener(&report),
+                is_safe_2(&report)
+            );
+        }
+    }
+
+    #[test]
+    fn example_incremental() {
+        let input = super::example::input();
+        let mut state = super::Incremental::new();
+
+        for len in [0, 7, 20, input.len()] {
+            super::incremental_solution(&mut state, &input[..len]).unwrap();
+        }
+
+        let answer = super::incremental_solution(&mut state, input).unwrap();
+        assert_eq!(
+            answer.part_1,
+            super::example::output_number_of_safe_reports_p_1()
+        );
+        assert_eq!(
+            answer.part_2,
+            super::example::output_number_of_safe_reports_p_2()
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_dampener_only_adds_safe_reports(input in crate::strategies::reports()) {
+            let answer = super::solution(&input).unwrap();
+            proptest::prop_assert!(answer.part_1 <= answer.part_2);
+        }
+
+        #[test]
+        fn prop_matches_reference(input in crate::strategies::reports()) {
+            let answer = super::solution(&input).unwrap();
+            let reference = super::reference_solution(&input).unwrap();
+            proptest::prop_assert_eq!(
+                (reference.part_1, reference.part_2),
+                (answer.part_1, answer.part_2)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(2), "example")
+    }
+
+    pub fn intermediate() -> Vec<Vec<i64>> {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_number_of_safe_reports_p_1() -> usize {
+        2
+    }
+
+    pub fn output_number_of_safe_reports_p_2() -> usize {
+        4
+    }
+
+    crate::golden::golden!(
+        golden,
+        input(),
+        part_1: output_number_of_safe_reports_p_1(),
+        part_2: output_number_of_safe_reports_p_2(),
+    );
+}
@@ -0,0 +1,238 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Mull It Over";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let instructions = phase::parsing(|| parse(input))?;
+    tracing::debug!(instructions = instructions.len(), "parsed instructions");
+    Ok(Answer {
+        part_1: solution::sum_of_results_of_the_multiplications_ignoring_do_dont(&instructions)
+            .into(),
+        part_2: solution::sum_of_results_of_the_multiplications(&instructions).into(),
+    })
+}
+
+/// Counts the instructions among the corrupted memory.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let instructions = parse(input)?;
+    let count = |is: fn(&Instruction) -> bool| instructions.iter().filter(|i| is(i)).count();
+    let mut report = Report::default();
+    report.note(format_args!(
+        "{} multiplications, {} do() and {} don't() instructions",
+        count(|i| matches!(i, Instruction::Mul(..))),
+        count(|i| *i == Instruction::Do),
+        count(|i| *i == Instruction::Dont),
+    ));
+    Ok(report)
+}
+
+/// Like [`solution`], but goes through the instructions as they are parsed
+/// rather than collecting them first, once for each part. Memory stays flat
+/// however long the input is, and parsing can't be timed on its own.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn streaming_solution(input: &str) -> Result<Answer, SolveError> {
+    Ok(Answer {
+        part_1: solution::sum_of_results_of_the_multiplications_ignoring_do_dont(
+            parser::instructions(input),
+        )
+        .into(),
+        part_2: solution::sum_of_results_of_the_multiplications(parser::instructions(input)).into(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Dont,
+    Do,
+    /// A byte of the corrupted memory that doesn't start an instruction.
+    Nop,
+}
+
+/// The instructions among the corrupted memory, junk included.
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::parse_util::{bytes, ByteParser, Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, Vec<super::Instruction>> {
+        bytes(nom::multi::many1(instruction()))
+    }
+
+    /// The instructions of `input` one by one, as they are parsed.
+    pub fn instructions(input: &str) -> impl Iterator<Item = super::Instruction> + '_ {
+        let mut instruction = instruction();
+        let mut instructions =
+            nom::combinator::iterator(input.as_bytes(), move |input| instruction.parse(input));
+        std::iter::from_fn(move || (&mut instructions).next())
+    }
+
+    fn instruction<'a>() -> impl ByteParser<'a, super::Instruction> {
+        nom::branch::alt((
+            mul(),
+            do_(),
+            dont(),
+            nom::bytes::complete::take(1usize).map(|_| super::Instruction::Nop),
+        ))
+    }
+
+    fn do_<'a>() -> impl ByteParser<'a, super::Instruction> {
+        nom::bytes::complete::tag("do()").map(|_| super::Instruction::Do)
+    }
+
+    fn dont<'a>() -> impl ByteParser<'a, super::Instruction> {
+        nom::bytes::complete::tag("don't()").map(|_| super::Instruction::Dont)
+    }
+
+    fn mul<'a>() -> impl ByteParser<'a, super::Instruction> {
+        nom::sequence::preceded(
+            nom::bytes::complete::tag("mul"),
+            nom::sequence::delimited(
+                nom::character::complete::char('('),
+                nom::sequence::separated_pair(
+                    nom::character::complete::i64,
+                    nom::character::complete::char(','),
+                    nom::character::complete::i64,
+                ),
+                nom::character::complete::char(')'),
+            ),
+        )
+        .map(|(l, r)| super::Instruction::Mul(l, r))
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(3), |rng| {
+            // Mostly the junk that sends the parser down its per-char path.
+            let mut line: String = (0..rng.between(20, 60))
+                .map(|_| rng.pick(&['x', 'm', 'u', 'l', '(', ')', ',', '!', '[', '1']))
+                .collect();
+            line += match rng.below(4) {
+                0 => "do()".to_owned(),
+                1 => "don't()".to_owned(),
+                _ => format!("mul({},{})", rng.between(1, 999), rng.between(1, 999)),
+            }
+            .as_str();
+            line
+        });
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::borrow::Borrow;
+
+    use super::Instruction;
+
+    pub fn sum_of_results_of_the_multiplications_ignoring_do_dont(
+        instructions: impl IntoIterator<Item = impl Borrow<Instruction>>,
+    ) -> i64 {
+        instructions
+            .into_iter()
+            .map(|instruction| match *instruction.borrow() {
+                Instruction::Mul(l, r) => l * r,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn sum_of_results_of_the_multiplications(
+        instructions: impl IntoIterator<Item = impl Borrow<Instruction>>,
+    ) -> i64 {
+        instructions
+            .into_iter()
+            .scan(
+                true,
+                |mul_enabled: &mut bool, instruction| match *instruction.borrow() {
+                    Instruction::Mul(l, r) => {
+                        if *mul_enabled {
+                            Some(l * r)
+                        } else {
+                            Some(0)
+                        }
+                    }
+                    Instruction::Do => {
+                        *mul_enabled = true;
+                        Some(0)
+                    }
+                    Instruction::Dont => {
+                        *mul_enabled = false;
+                        Some(0)
+                    }
+                    _ => Some(0),
+                },
+            )
+            .sum()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            sum_of_results_of_the_multiplications_ignoring_do_dont(
+                super::example::intermediate_p_1()
+            ),
+            super::example::output_p_1()
+        );
+        assert_eq!(
+            sum_of_results_of_the_multiplications(super::example::intermediate_p_2()),
+            super::example::output_p_2()
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        let answer = super::streaming_solution(super::example::input_p_1()).unwrap();
+        assert_eq!(super::example::output_p_1(), answer.part_1);
+        let answer = super::streaming_solution(super::example::input_p_2()).unwrap();
+        assert_eq!(super::example::output_p_2(), answer.part_2);
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::Instruction;
+    pub fn input_p_1() -> &'static str {
+        examples::text(Day::new(3), "example.1")
+    }
+
+    pub fn intermediate_p_1() -> Vec<Instruction> {
+        crate::golden::parse(super::parser::input(), input_p_1())
+    }
+
+    pub fn output_p_1() -> i64 {
+        161
+    }
+
+    pub fn input_p_2() -> &'static str {
+        examples::text(Day::new(3), "example.2")
+    }
+
+    pub fn intermediate_p_2() -> Vec<Instruction> {
+        crate::golden::parse(super::parser::input(), input_p_2())
+    }
+
+    pub fn output_p_2() -> i64 {
+        48
+    }
+
+    crate::golden::golden!(golden_p_1, input_p_1(), part_1: output_p_1());
+    crate::golden::golden!(golden_p_2, input_p_2(), part_2: output_p_2());
+}
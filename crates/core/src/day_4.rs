@@ -0,0 +1,238 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    grid::Grid,
+    phase,
+    validate::{grid_shape, Report},
+};
+
+pub const TITLE: &str = "Ceres Search";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(size = ?input.size(), "parsed word search");
+
+    Ok(Answer {
+        part_1: solution::part_1::count_xmas(&input).into(),
+        part_2: solution::part_2::count_xmas(&input).into(),
+    })
+}
+
+/// Checks that the grid is rectangular and parses it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    if grid_shape(input, &mut report) {
+        parse(input)?;
+    }
+    Ok(report)
+}
+
+/// The word search.
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::{
+        grid::Grid,
+        parse_util::{bytes, Parser},
+    };
+
+    pub fn input<'a>() -> impl Parser<'a, Grid<char>> {
+        bytes(Grid::parse_bytes(nom::character::complete::satisfy(|ch| {
+            "XMAS".contains(ch)
+        })))
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(&mut Rng::new(4), |rng| rng.pick(&['X', 'M', 'A', 'S']), &[]);
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use guard::guard;
+
+    use crate::grid::{Grid, Position};
+
+    fn check_position(grid: &Grid<char>, position: (i32, i32), expected_char: char) -> bool {
+        let (row_idx, col_idx) = position;
+        guard! {
+            let (Ok(row_idx), Ok(col_idx)) = (row_idx.try_into(), col_idx.try_into()) else {
+                return false
+            }
+        };
+
+        grid.get(Position::new(row_idx, col_idx)) == Some(&expected_char)
+    }
+
+    fn make_positions<const C: usize>(
+        current_position: Position,
+        offset: [(i32 /* row */, i32 /* col */); C],
+    ) -> [(i32 /* row */, i32 /* col */); C] {
+        offset.map(|(row_offset, col_offset)| {
+            (
+                current_position.row_index as i32 + row_offset,
+                current_position.col_index as i32 + col_offset,
+            )
+        })
+    }
+
+    pub mod part_1 {
+        use super::{check_position, make_positions};
+        use crate::grid::Grid;
+
+        fn check_xmas_sequence(
+            grid: &Grid<char>,
+            positions: [(i32 /* row */, i32 /* col */); 4],
+        ) -> bool {
+            let [x_pos, m_pos, a_pos, s_pos] = positions;
+
+            check_position(grid, x_pos, 'X')
+                && check_position(grid, m_pos, 'M')
+                && check_position(grid, a_pos, 'A')
+                && check_position(grid, s_pos, 'S')
+        }
+
+        pub fn count_xmas(grid: &Grid<char>) -> usize {
+            let mut count = 0;
+
+            let offsets: [[(i32 /* row */, i32 /* col */); 4]; 8] = [
+                [(0, 0), (0, -1), (0, -2), (0, -3)],    // left
+                [(0, 0), (0, 1), (0, 2), (0, 3)],       // right
+                [(0, 0), (-1, 0), (-2, 0), (-3, 0)],    // up
+                [(0, 0), (1, 0), (2, 0), (3, 0)],       // down
+                [(0, 0), (-1, -1), (-2, -2), (-3, -3)], // upper left
+                [(0, 0), (-1, 1), (-2, 2), (-3, 3)],    // upper right
+                [(0, 0), (1, -1), (2, -2), (3, -3)],    // lower left
+                [(0, 0), (1, 1), (2, 2), (3, 3)],       // lower right
+            ];
+
+            for position in grid.positions() {
+                for offset in offsets {
+                    let positions = make_positions(position, offset);
+                    if check_xmas_sequence(&grid, positions) {
+                        count += 1
+                    }
+                }
+            }
+
+            count
+        }
+
+        #[test]
+        fn example() {
+            assert_eq!(
+                count_xmas(&super::super::example::intermediate()),
+                super::super::example::output_p_1()
+            );
+        }
+    }
+
+    pub mod part_2 {
+        use crate::grid::{Grid, GridView, Position};
+
+        /// Counts the places where two copies of `word` cross diagonally in
+        /// an X, sharing its middle letter. Either stroke may read backwards.
+        pub fn count_crosses(grid: &Grid<char>, word: &str) -> usize {
+            let word: Vec<char> = word.chars().collect();
+            assert!(
+                word.len() % 2 == 1,
+                "a cross needs a word with a middle letter"
+            );
+            let size = word.len();
+
+            // Whether the window's diagonal (or antidiagonal) spells `word`,
+            // forwards or backwards.
+            let spells_word = |window: &GridView<char>, antidiagonal: bool| {
+                let diagonal = (0..size).map(|index| {
+                    let col_index = if antidiagonal {
+                        size - 1 - index
+                    } else {
+                        index
+                    };
+                    window[Position::new(index, col_index)]
+                });
+                diagonal.clone().eq(word.iter().copied()) || diagonal.eq(word.iter().rev().copied())
+            };
+
+            grid.windows(size, size)
+                .filter(|window| spells_word(window, false) && spells_word(window, true))
+                .count()
+        }
+
+        pub fn count_xmas(grid: &Grid<char>) -> usize {
+            count_crosses(grid, "MAS")
+        }
+
+        #[test]
+        fn example() {
+            assert_eq!(
+                count_xmas(&super::super::example::intermediate()),
+                super::super::example::output_p_2()
+            );
+        }
+
+        #[test]
+        fn longer_words() {
+            let grid =
+                |rows: &[&str]| Grid::new(rows.iter().map(|row| row.chars().collect()).collect());
+
+            let crossed = grid(&["A...E", ".B.D.", "..C..", ".B.D.", "A...E"]);
+            assert_eq!(count_crosses(&crossed, "ABCDE"), 1);
+            assert_eq!(count_crosses(&crossed, "EDCBA"), 1);
+            assert_eq!(count_crosses(&crossed, "BCD"), 1);
+            assert_eq!(count_crosses(&crossed, "ABCDEFG"), 0);
+
+            let one_stroke = grid(&["A...E", ".B.D.", "..C..", ".X.D.", "A...E"]);
+            assert_eq!(count_crosses(&one_stroke, "ABCDE"), 0);
+
+            let palindrome = grid(&[
+                "X.....X", ".M...M.", "..A.A..", "...S...", "..A.A..", ".M...M.", "X.....X",
+            ]);
+            assert_eq!(count_crosses(&palindrome, "XMASAMX"), 1);
+            assert_eq!(count_crosses(&palindrome, "ASA"), 1);
+        }
+
+        #[test]
+        #[should_panic]
+        fn even_length_word() {
+            count_crosses(&Grid::new(vec![vec!['A']]), "AB");
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use crate::grid::Grid;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(4), "example")
+    }
+
+    pub fn intermediate() -> Grid<char> {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_p_1() -> usize {
+        18
+    }
+
+    pub fn output_p_2() -> usize {
+        9
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
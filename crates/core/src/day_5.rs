@@ -0,0 +1,513 @@
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    graph::Graph,
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Print Queue";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(
+        rules = input.page_ordering_rules.len(),
+        updates = input.updates.len(),
+        "parsed print queue"
+    );
+
+    Ok(Answer {
+        part_1: solution::sum_of_middle_page_numbers_of_valid_updates(&input).into(),
+        part_2: solution::sum_of_middle_page_numbers_of_fixed_invalid_updates(&input)?.into(),
+    })
+}
+
+/// Parses the rules and updates and checks that the rules put the pages of
+/// every update in a single order, see [`explain`] for the details.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let input = parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!(
+        "{} ordering rules, {} updates",
+        input.page_ordering_rules.len(),
+        input.updates.len()
+    ));
+
+    for (update_idx, update) in input.updates.iter().enumerate() {
+        if update.len() % 2 == 0 {
+            report.problem(format_args!(
+                "update {update_idx} has an even number of pages, so no middle one"
+            ));
+        }
+    }
+    let analysis = solution::analyze_rules(&input);
+    for (update_idx, l, r) in &analysis.contradictory_pairs {
+        report.problem(format_args!(
+            "update {update_idx}: the rules order pages {l} and {r} both ways"
+        ));
+    }
+    for (update_idx, l, r) in &analysis.incomparable_pairs {
+        report.problem(format_args!(
+            "update {update_idx}: no rule orders pages {l} and {r}"
+        ));
+    }
+    Ok(report)
+}
+
+/// Checks whether the ordering rules decide every pair of pages within each
+/// update, i.e. whether sorting an update with them as a comparator is sound.
+pub fn explain(input: &str) -> Result<String, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+
+    Ok(solution::analyze_rules(&input).to_string())
+}
+
+/// The ordering rules as a Graphviz DOT graph, or with `update` only the
+/// rules between the pages of that update, counting from 0. Rules the update
+/// breaks are red and the rules of a cycle, if there's one, bold.
+pub fn dot(input: &str, update: Option<usize>) -> Result<String, DotError> {
+    let input = phase::parsing(|| parse(input))?;
+    let rules_graph = Graph::with_edges(&input.page_ordering_rules);
+    let pages = match update {
+        Some(update_idx) => {
+            input
+                .updates
+                .get(update_idx)
+                .cloned()
+                .ok_or(DotError::NoSuchUpdate {
+                    update: update_idx,
+                    updates: input.updates.len(),
+                })?
+        }
+        None => rules_graph.nodes().copied().collect(),
+    };
+    let graph = rules_graph.subgraph(&pages);
+
+    let cycle = graph.find_cycle().unwrap_or_default();
+    let in_cycle = |src: &i64, dest: &i64| {
+        cycle
+            .iter()
+            .zip(cycle.iter().cycle().skip(1))
+            .any(|edge| edge == (src, dest))
+    };
+    let position = |page: &i64| pages.iter().position(|p| p == page);
+    Ok(graph.to_dot(|src, dest| {
+        let broken = update.is_some() && position(dest) < position(src);
+        let attributes = [
+            broken.then_some("color=red"),
+            in_cycle(src, dest).then_some("style=bold"),
+        ];
+        let attributes = attributes.into_iter().flatten().join(", ");
+        (!attributes.is_empty()).then_some(attributes)
+    }))
+}
+
+/// Why [`dot`] has no graph to draw.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DotError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("there's no update {update}, only {updates} of them")]
+    NoSuchUpdate { update: usize, updates: usize },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    /// `(l, r)` for a rule `l|r`, page `l` goes before page `r`.
+    pub page_ordering_rules: Vec<(i64, i64)>,
+    pub updates: Vec<Vec<i64>>,
+}
+
+/// The ordering rules and the updates.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use super::Input;
+    use crate::parse_util::{blank_lines, lines, Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, Input> {
+        nom::sequence::separated_pair(page_ordering_rules(), blank_lines(), updates()).map(
+            |(page_ordering_rules, updates)| Input {
+                page_ordering_rules,
+                updates,
+            },
+        )
+    }
+
+    fn page_ordering_rules<'a>() -> impl Parser<'a, Vec<(i64, i64)>> {
+        lines(page_ordering_rule())
+    }
+
+    fn page_ordering_rule<'a>() -> impl Parser<'a, (i64, i64)> {
+        nom::sequence::separated_pair(
+            nom::character::complete::i64,
+            nom::character::complete::char('|'),
+            nom::character::complete::i64,
+        )
+    }
+
+    fn updates<'a>() -> impl Parser<'a, Vec<Vec<i64>>> {
+        lines(update())
+    }
+
+    fn update<'a>() -> impl Parser<'a, Vec<i64>> {
+        nom::multi::separated_list1(
+            nom::character::complete::char(','),
+            nom::character::complete::i64,
+        )
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(5), |rng| {
+            format!("{}|{}", rng.between(10, 99), rng.between(10, 99))
+        }) + "\n"
+            + &lines(LINES, &mut Rng::new(55), |rng| {
+                (0..rng.between(2, 11) * 2 + 1)
+                    .map(|_| rng.between(10, 99).to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            });
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        fmt::{self, Display},
+        ops::Not,
+    };
+
+    use super::Input;
+    use crate::{error::SolveError, graph::Graph};
+
+    fn make_disallowed_in_suffix_map(
+        page_ordering_rules: &[(i64, i64)],
+    ) -> BTreeMap<i64, BTreeSet<i64>> {
+        page_ordering_rules
+            .iter()
+            .fold(BTreeMap::<i64, BTreeSet<i64>>::new(), |mut acc, (l, r)| {
+                acc.entry(*r).or_default().insert(*l);
+                acc
+            })
+    }
+
+    fn is_valid_update(
+        disallowed_in_suffix_map: &BTreeMap<i64, BTreeSet<i64>>,
+        update: &[i64],
+    ) -> bool {
+        let mut all_disallowed = BTreeSet::<i64>::new();
+
+        for page in update {
+            if all_disallowed.contains(page) {
+                return false;
+            }
+            if let Some(disallowed) = disallowed_in_suffix_map.get(page) {
+                all_disallowed.append(&mut disallowed.clone());
+            }
+        }
+
+        return true;
+    }
+
+    fn middle_page_number(update: &[i64]) -> i64 {
+        update[update.len() / 2]
+    }
+
+    pub fn sum_of_middle_page_numbers_of_valid_updates(input: &Input) -> i64 {
+        let disallowed_in_suffix_map = make_disallowed_in_suffix_map(&input.page_ordering_rules);
+        input
+            .updates
+            .iter()
+            .filter_map(|update| {
+                is_valid_update(&disallowed_in_suffix_map, update)
+                    .then_some(middle_page_number(update))
+            })
+            .sum()
+    }
+
+    fn fix_update(rules_graph: &Graph<i64>, update: &[i64]) -> Result<Vec<i64>, SolveError> {
+        let subgraph = rules_graph.subgraph(update);
+        if let Some(path) = subgraph.hamiltonian_path() {
+            return Ok(path);
+        }
+
+        match subgraph.find_cycle() {
+            Some(cycle) => Err(SolveError::RuleCycle {
+                update: update.to_vec(),
+                cycle,
+            }),
+            None => Err(SolveError::UndecidedOrder {
+                update: update.to_vec(),
+            }),
+        }
+    }
+
+    pub fn sum_of_middle_page_numbers_of_fixed_invalid_updates(
+        input: &Input,
+    ) -> Result<i64, SolveError> {
+        let disallowed_in_suffix_map = make_disallowed_in_suffix_map(&input.page_ordering_rules);
+        let rules_graph = Graph::with_edges(&input.page_ordering_rules);
+
+        input
+            .updates
+            .iter()
+            .filter(|update| is_valid_update(&disallowed_in_suffix_map, update).not())
+            .map(|update| Ok(middle_page_number(&fix_update(&rules_graph, update)?)))
+            .sum()
+    }
+
+    /// How the ordering rules relate the pages of each update, with the
+    /// rules taken transitively but only through pages of the same update.
+    /// The real rule set is cyclic as a whole, so only chains within an
+    /// update mean anything.
+    #[derive(Debug, Default, PartialEq, Eq)]
+    pub struct RuleAnalysis {
+        pub updates: usize,
+        /// `(update index, page, page)` ordered neither way.
+        pub incomparable_pairs: Vec<(usize, i64, i64)>,
+        /// `(update index, page, page)` ordered only through a chain of
+        /// rules, a comparator looking rules up directly gets these wrong.
+        pub indirect_pairs: Vec<(usize, i64, i64)>,
+        /// `(update index, page, page)` ordered both ways.
+        pub contradictory_pairs: Vec<(usize, i64, i64)>,
+    }
+
+    impl RuleAnalysis {
+        /// Whether a comparator built from the rules is a total order on
+        /// every update.
+        pub fn comparator_is_total(&self) -> bool {
+            self.incomparable_pairs.is_empty()
+                && self.indirect_pairs.is_empty()
+                && self.contradictory_pairs.is_empty()
+        }
+    }
+
+    impl Display for RuleAnalysis {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let sections = [
+                ("incomparable", &self.incomparable_pairs),
+                ("ordered only transitively", &self.indirect_pairs),
+                ("ordered both ways", &self.contradictory_pairs),
+            ];
+            for (label, pairs) in sections {
+                writeln!(f, "{} page pairs {label}", pairs.len())?;
+                for (update_idx, l, r) in pairs {
+                    writeln!(f, "  update {update_idx}: {l} and {r}")?;
+                }
+            }
+            if self.comparator_is_total() {
+                write!(
+                    f,
+                    "the rules totally order all {} updates, sorting by them is sound",
+                    self.updates
+                )
+            } else {
+                write!(
+                    f,
+                    "the rules don't totally order every update, sorting by them is unsound"
+                )
+            }
+        }
+    }
+
+    /// Computes the transitive closure of the rules among the pages of each
+    /// update and collects the pairs it doesn't decide exactly once.
+    pub fn analyze_rules(input: &Input) -> RuleAnalysis {
+        let rules: BTreeSet<(i64, i64)> = input.page_ordering_rules.iter().copied().collect();
+        let mut analysis = RuleAnalysis {
+            updates: input.updates.len(),
+            ..Default::default()
+        };
+
+        for (update_idx, update) in input.updates.iter().enumerate() {
+            let pages: Vec<i64> = update
+                .iter()
+                .copied()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let n = pages.len();
+            let mut before = vec![vec![false; n]; n];
+            for (i, l) in pages.iter().enumerate() {
+                for (j, r) in pages.iter().enumerate() {
+                    before[i][j] = rules.contains(&(*l, *r));
+                }
+            }
+            // Warshall's algorithm.
+            for k in 0..n {
+                let through_k = before[k].clone();
+                for row in before.iter_mut().filter(|row| row[k]) {
+                    for (reachable, via_k) in row.iter_mut().zip(&through_k) {
+                        *reachable |= via_k;
+                    }
+                }
+            }
+
+            for i in 0..n {
+                for j in i + 1..n {
+                    let (l, r) = (pages[i], pages[j]);
+                    let pair = (update_idx, l, r);
+                    match (before[i][j], before[j][i]) {
+                        (false, false) => analysis.incomparable_pairs.push(pair),
+                        (true, true) => analysis.contradictory_pairs.push(pair),
+                        _ if !rules.contains(&(l, r)) && !rules.contains(&(r, l)) => {
+                            analysis.indirect_pairs.push(pair)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        analysis
+    }
+
+    #[test]
+    fn rule_analysis() {
+        assert!(analyze_rules(&super::example::intermediate()).comparator_is_total());
+
+        let input = Input {
+            page_ordering_rules: vec![(1, 2), (2, 3), (4, 5), (5, 4)],
+            updates: vec![vec![1, 2], vec![3, 1, 2], vec![1, 4], vec![4, 5]],
+        };
+        assert_eq!(
+            RuleAnalysis {
+                updates: 4,
+                incomparable_pairs: vec![(2, 1, 4)],
+                indirect_pairs: vec![(1, 1, 3)],
+                contradictory_pairs: vec![(3, 4, 5)],
+            },
+            analyze_rules(&input)
+        );
+    }
+
+    #[test]
+    fn example_is_valid_update() {
+        let input = super::example::intermediate();
+        let disallowed_in_suffix_map = make_disallowed_in_suffix_map(&input.page_ordering_rules);
+        let check_update = |idx: usize, expected_validity: bool| {
+            let update = &input.updates[idx];
+            let is_valid = is_valid_update(&disallowed_in_suffix_map, update);
+            assert_eq!(
+                is_valid, expected_validity,
+                "idx = {idx}, update = {update:?}"
+            )
+        };
+        check_update(0, true);
+        check_update(1, true);
+        check_update(2, true);
+        check_update(3, false);
+        check_update(4, false);
+        check_update(5, false);
+    }
+
+    #[test]
+    fn example_fix_update() {
+        let input = super::example::intermediate();
+        let graph = Graph::with_edges(&input.page_ordering_rules);
+        let disallowed_in_suffix_map = make_disallowed_in_suffix_map(&input.page_ordering_rules);
+        let check_fixed_update = |idx: usize, expected_fixed_update: Vec<i64>| {
+            let update = &input.updates[idx];
+            let fixed_update = fix_update(&graph, update).unwrap();
+            assert_eq!(
+                fixed_update, expected_fixed_update,
+                "idx = {idx}, update = {update:?}"
+            );
+            assert!(is_valid_update(&disallowed_in_suffix_map, &fixed_update));
+        };
+        check_fixed_update(3, vec![97, 75, 47, 61, 53]);
+        check_fixed_update(4, vec![61, 29, 13]);
+        check_fixed_update(5, vec![97, 75, 47, 29, 13]);
+    }
+
+    #[test]
+    fn unfixable_update() {
+        let graph = Graph::with_edges(&[(1, 2), (2, 3), (3, 1), (4, 5)]);
+
+        assert_eq!(
+            "the rules for update [3, 2, 1] form a cycle 1 -> 2 -> 3 -> 1",
+            fix_update(&graph, &[3, 2, 1]).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "the rules don't decide the order of every page in update [5, 1]",
+            fix_update(&graph, &[5, 1]).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            super::example::output_p_1(),
+            sum_of_middle_page_numbers_of_valid_updates(&super::example::intermediate())
+        );
+        assert_eq!(
+            super::example::output_p_2(),
+            sum_of_middle_page_numbers_of_fixed_invalid_updates(&super::example::intermediate())
+                .unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::Input;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(5), "example")
+    }
+
+    pub fn intermediate() -> Input {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_p_1() -> i64 {
+        143
+    }
+
+    pub fn output_p_2() -> i64 {
+        123
+    }
+
+    #[test]
+    fn dot() {
+        let dot = super::dot(input(), None).unwrap();
+        assert_eq!(21, dot.matches(" -> ").count());
+        assert!(!dot.contains('['));
+
+        let dot = super::dot(input(), Some(4)).unwrap();
+        assert_eq!(3, dot.matches(" -> ").count());
+        assert!(dot.contains(r#""29" -> "13" [color=red];"#));
+        assert!(dot.contains(r#""61" -> "13";"#));
+
+        assert_eq!(
+            Err(super::DotError::NoSuchUpdate {
+                update: 6,
+                updates: 6
+            }),
+            super::dot(input(), Some(6))
+        );
+
+        let dot = super::dot("1|2\n2|3\n3|1\n\n3,2,1", Some(0)).unwrap();
+        assert!(dot.contains(r#""1" -> "2" [color=red, style=bold];"#));
+        assert!(dot.contains(r#""3" -> "1" [style=bold];"#));
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
@@ -0,0 +1,600 @@
+use anstyle::AnsiColor;
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    grid::Direction,
+    phase,
+    validate::{exactly_one, grid_shape, Report},
+    viz,
+};
+
+pub const TITLE: &str = "Guard Gallivant";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(rows = input.map.len(), "parsed lab map");
+
+    Ok(Answer {
+        part_1: solution::move_guard_until_out_of_bound(&input).into(),
+        part_2: solution::number_of_obstructions_that_causes_looping(&input).into(),
+    })
+}
+
+/// Checks that the map is rectangular with a single guard on it, and parses
+/// it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    let rectangular = grid_shape(input, &mut report);
+    exactly_one(input, "guard", |ch| "^>v<".contains(ch), &mut report);
+    if rectangular && report.is_valid() {
+        parse(input)?;
+    }
+    Ok(report)
+}
+
+/// Shows the guard's patrol in the puzzle's notation, `|` and `-` where it
+/// walked up or down and left or right, `+` where it did both or turned.
+/// Part 1 shows every step, part 2 only the finished patrol with an `O` on
+/// each of the positions where a new obstruction would make the guard loop.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let input = parse(input)?;
+    let mut tiles = input
+        .map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    Cell::Obstruction => '#',
+                    Cell::Empty => '.',
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    let walk = |tiles: &mut Vec<Vec<char>>, (position, direction): (Position, Direction)| {
+        let tile = &mut tiles[position.row_index as usize][position.col_index as usize];
+        let vertical = matches!(direction, Direction::Up | Direction::Down);
+        *tile = match (*tile, vertical) {
+            ('.' | '|', true) => '|',
+            ('.' | '-', false) => '-',
+            _ => '+',
+        };
+    };
+
+    let patrol = solution::patrol(&input);
+    if options.part == 1 {
+        for &step in &patrol {
+            walk(&mut tiles, step);
+            sink.frame(&render_lab(&tiles, Some(step), options.color))?;
+        }
+        return Ok(());
+    }
+
+    patrol.into_iter().for_each(|step| walk(&mut tiles, step));
+    for position in solution::obstructions_that_cause_looping(&input) {
+        tiles[position.row_index as usize][position.col_index as usize] = 'O';
+    }
+    Ok(sink.frame(&render_lab(&tiles, None, options.color))?)
+}
+
+/// Draws `tiles` with the guard on top of them if it's still in the lab.
+fn render_lab(tiles: &[Vec<char>], guard: Option<(Position, Direction)>, color: bool) -> String {
+    let guard_style = AnsiColor::Yellow.on_default().bold();
+    let obstruction_style = AnsiColor::Red.on_default();
+    tiles
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(col_index, &tile)| {
+                    let position = Position {
+                        row_index: row_index as i64,
+                        col_index: col_index as i64,
+                    };
+                    let (tile, style) = match guard {
+                        Some((guard, direction)) if guard == position => {
+                            (direction.to_arrow(), guard_style)
+                        }
+                        _ if tile == 'O' => (tile, obstruction_style),
+                        _ => return tile.to_string(),
+                    };
+                    if color {
+                        format!("{style}{tile}{style:#}")
+                    } else {
+                        tile.to_string()
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Input {
+    pub guard_initial_direction: Direction,
+    pub guard_initial_position: Position,
+    /// Rows of cells, where the guard starts is empty.
+    pub map: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub row_index: i64,
+    pub col_index: i64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Obstruction,
+    Empty,
+}
+
+/// The map of the lab with the guard taken off it.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use itertools::Itertools;
+
+    use super::{Cell, Direction, Input, Position};
+    use crate::{grid::Grid, parse_util::Parser};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum IntermediateCell {
+        Obstruction,
+        Empty,
+        Guard(Direction),
+    }
+
+    pub fn input<'a>() -> impl Parser<'a, Input> {
+        nom::combinator::map_res(Grid::parse(intermediate_cell()), intermediate_map_to_input)
+    }
+
+    fn intermediate_map_to_input(map: Grid<IntermediateCell>) -> Result<Input, String> {
+        let (guard_initial_position, guard_initial_direction) = map
+            .iter()
+            .filter_map(|(position, cell)| match cell {
+                IntermediateCell::Guard(direction) => Some((
+                    Position {
+                        row_index: position.row_index.try_into().unwrap(),
+                        col_index: position.col_index.try_into().unwrap(),
+                    },
+                    *direction,
+                )),
+                _ => None,
+            })
+            .exactly_one()
+            .map_err(|_| "more than one guard found".to_string())?;
+
+        let map = map
+            .map(|cell| match cell {
+                IntermediateCell::Empty | IntermediateCell::Guard(_) => Cell::Empty,
+                IntermediateCell::Obstruction => Cell::Obstruction,
+            })
+            .into_rows();
+
+        Ok(Input {
+            guard_initial_direction,
+            guard_initial_position,
+            map,
+        })
+    }
+
+    fn intermediate_cell<'a>() -> impl Parser<'a, IntermediateCell> {
+        nom::combinator::map_res(
+            nom::character::complete::anychar,
+            |ch: char| -> Result<IntermediateCell, String> {
+                match ch {
+                    '.' => Ok(IntermediateCell::Empty),
+                    '#' => Ok(IntermediateCell::Obstruction),
+                    ch => {
+                        let direction = match ch {
+                            '^' => Ok(Direction::Up),
+                            '>' => Ok(Direction::Right),
+                            'v' => Ok(Direction::Down),
+                            '<' => Ok(Direction::Left),
+                            ch => Err(format!("invalid character on map: {ch}")),
+                        }?;
+                        Ok(IntermediateCell::Guard(direction))
+                    }
+                }
+            },
+        )
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(
+            &mut Rng::new(6),
+            |rng| if rng.below(10) == 0 { '#' } else { '.' },
+            &['^'],
+        );
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use itertools::Itertools;
+
+    use super::{Cell, Direction, Input, Position};
+    use crate::par::prelude::*;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum PositionValidity {
+        Valid,
+        InObstruction,
+        OutOfBound,
+    }
+
+    impl Position {
+        fn advance(&self, in_direction: Direction) -> Self {
+            let offset = in_direction.to_offset();
+            Self {
+                row_index: self.row_index + offset.row_offset as i64,
+                col_index: self.col_index + offset.col_offset as i64,
+            }
+        }
+
+        fn grab_cell(&self, map: &Vec<Vec<Cell>>) -> Option<Cell> {
+            let row_index = usize::try_from(self.row_index).ok()?;
+            let col_index = usize::try_from(self.col_index).ok()?;
+            let col = map.get(row_index)?;
+            col.get(col_index).copied()
+        }
+
+        /// `extra_obstruction` is treated as an obstruction on top of `map`.
+        fn check_validity(
+            &self,
+            map: &Vec<Vec<Cell>>,
+            extra_obstruction: Option<Position>,
+        ) -> PositionValidity {
+            if extra_obstruction == Some(*self) {
+                return PositionValidity::InObstruction;
+            }
+            match self.grab_cell(map) {
+                Some(cell) => match cell {
+                    Cell::Obstruction => PositionValidity::InObstruction,
+                    Cell::Empty => PositionValidity::Valid,
+                },
+                None => PositionValidity::OutOfBound,
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    struct GuardState {
+        direction: Direction,
+        current_position: Position,
+    }
+
+    impl GuardState {
+        fn advance(
+            &self,
+            map: &Vec<Vec<Cell>>,
+            extra_obstruction: Option<Position>,
+        ) -> Option<GuardState> {
+            let next_position = self.current_position.advance(self.direction);
+            match next_position.check_validity(map, extra_obstruction) {
+                PositionValidity::Valid => Some(GuardState {
+                    direction: self.direction,
+                    current_position: next_position,
+                }),
+                PositionValidity::InObstruction => Some(GuardState {
+                    direction: self.direction.turn_right(),
+                    current_position: self.current_position,
+                }),
+                PositionValidity::OutOfBound => None,
+            }
+        }
+    }
+
+    /// Small integers as one bit each, much faster to look up than a hash set
+    /// for the few thousand positions and states of a patrol.
+    struct BitSet(Vec<u64>);
+
+    impl BitSet {
+        fn new(len: usize) -> Self {
+            Self(vec![0; len.div_ceil(64)])
+        }
+
+        /// Whether `index` wasn't in the set yet.
+        fn insert(&mut self, index: usize) -> bool {
+            let (word, bit) = (index / 64, 1 << (index % 64));
+            let inserted = self.0[word] & bit == 0;
+            self.0[word] |= bit;
+            inserted
+        }
+    }
+
+    fn cell_count(map: &[Vec<Cell>]) -> usize {
+        map.len() * map.first().map_or(0, |row| row.len())
+    }
+
+    /// Index of an in-bounds `position` in row order.
+    fn position_index(position: Position, map: &[Vec<Cell>]) -> usize {
+        position.row_index as usize * map[0].len() + position.col_index as usize
+    }
+
+    fn state_index(state: GuardState, map: &[Vec<Cell>]) -> usize {
+        position_index(state.current_position, map) * Direction::ALL.len()
+            + state.direction as usize
+    }
+
+    fn move_guard_until_out_of_bound_state_sequence(input: &Input) -> Vec<GuardState> {
+        let mut guard_states = Vec::<GuardState>::new();
+        let mut guard_state = GuardState {
+            direction: input.guard_initial_direction,
+            current_position: input.guard_initial_position,
+        };
+
+        loop {
+            guard_states.push(guard_state);
+            match guard_state.advance(&input.map, None) {
+                Some(next_guard_state) => guard_state = next_guard_state,
+                None => break,
+            }
+        }
+
+        guard_states
+    }
+
+    pub fn move_guard_until_out_of_bound(input: &Input) -> usize {
+        let mut visited_positions = BitSet::new(cell_count(&input.map));
+        move_guard_until_out_of_bound_state_sequence(input)
+            .into_iter()
+            .filter(|guard_state| {
+                visited_positions.insert(position_index(guard_state.current_position, &input.map))
+            })
+            .count()
+    }
+
+    fn move_guard_while_detecting_looping(
+        map: &Vec<Vec<Cell>>,
+        extra_obstruction: Option<Position>,
+        mut guard_state: GuardState,
+    ) -> bool {
+        let mut unique_guard_states = BitSet::new(cell_count(map) * Direction::ALL.len());
+        loop {
+            if !unique_guard_states.insert(state_index(guard_state, map)) {
+                return true;
+            }
+            match guard_state.advance(map, extra_obstruction) {
+                Some(next_guard_state) => guard_state = next_guard_state,
+                None => break,
+            }
+        }
+        return false;
+    }
+
+    /// Only cells the guard actually walks onto can change its patrol. Pairs
+    /// each of them with the state right before the guard first steps onto
+    /// it: up to that point the extra obstruction makes no difference, so the
+    /// loop check can resume from there instead of from the very start.
+    fn potential_additional_obstruction_positions(
+        map: &[Vec<Cell>],
+        guard_states_without_addition_obstruction: &[GuardState],
+    ) -> Vec<(Position, GuardState)> {
+        let mut visited_positions = BitSet::new(cell_count(map));
+        if let Some(initial_state) = guard_states_without_addition_obstruction.first() {
+            visited_positions.insert(position_index(initial_state.current_position, map));
+        }
+
+        guard_states_without_addition_obstruction
+            .iter()
+            .tuple_windows()
+            .filter(|(_, state)| {
+                visited_positions.insert(position_index(state.current_position, map))
+            })
+            .map(|(state_before_contact, state)| (state.current_position, *state_before_contact))
+            .collect()
+    }
+
+    /// Where the guard is and which way it faces at every step until it
+    /// leaves the lab. Only used to visualize.
+    pub fn patrol(input: &Input) -> Vec<(Position, Direction)> {
+        move_guard_until_out_of_bound_state_sequence(input)
+            .into_iter()
+            .map(|state| (state.current_position, state.direction))
+            .collect()
+    }
+
+    pub fn number_of_obstructions_that_causes_looping(input: &Input) -> usize {
+        obstructions_that_cause_looping(input).len()
+    }
+
+    pub fn obstructions_that_cause_looping(input: &Input) -> Vec<Position> {
+        let original_state_sequence = move_guard_until_out_of_bound_state_sequence(input);
+        let potential_positions =
+            potential_additional_obstruction_positions(&input.map, &original_state_sequence);
+        tracing::debug!(
+            steps = original_state_sequence.len(),
+            candidates = potential_positions.len(),
+            "checking obstruction candidates"
+        );
+
+        potential_positions
+            .into_par_iter()
+            .filter(|(position, state_before_contact)| {
+                move_guard_while_detecting_looping(
+                    &input.map,
+                    Some(*position),
+                    *state_before_contact,
+                )
+            })
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    #[test]
+    fn resumes_from_first_contact() {
+        let input = super::example::intermediate();
+        let original_state_sequence = move_guard_until_out_of_bound_state_sequence(&input);
+        let potential_positions =
+            potential_additional_obstruction_positions(&input.map, &original_state_sequence);
+
+        assert_eq!(
+            move_guard_until_out_of_bound(&input) - 1,
+            potential_positions.len()
+        );
+        assert!(potential_positions
+            .iter()
+            .all(|(position, _)| *position != input.guard_initial_position));
+        for (position, state_before_contact) in potential_positions {
+            assert_eq!(
+                move_guard_while_detecting_looping(
+                    &input.map,
+                    Some(position),
+                    GuardState {
+                        direction: input.guard_initial_direction,
+                        current_position: input.guard_initial_position,
+                    }
+                ),
+                move_guard_while_detecting_looping(
+                    &input.map,
+                    Some(position),
+                    state_before_contact
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn extra_obstruction_matches_modified_map() {
+        let input = super::example::intermediate();
+        let initial_state = GuardState {
+            direction: input.guard_initial_direction,
+            current_position: input.guard_initial_position,
+        };
+        for (row_index, col_index) in (0..input.map.len()).cartesian_product(0..input.map[0].len())
+        {
+            let position = Position {
+                row_index: row_index as i64,
+                col_index: col_index as i64,
+            };
+            if position == input.guard_initial_position {
+                continue;
+            }
+            let mut map = input.map.clone();
+            map[row_index][col_index] = Cell::Obstruction;
+            assert_eq!(
+                move_guard_while_detecting_looping(&map, None, initial_state),
+                move_guard_while_detecting_looping(&input.map, Some(position), initial_state)
+            );
+        }
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            super::example::output_p_1(),
+            move_guard_until_out_of_bound(&super::example::intermediate())
+        );
+        assert_eq!(
+            super::example::output_p_2(),
+            number_of_obstructions_that_causes_looping(&super::example::intermediate())
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_obstructions_are_on_the_patrol(input in crate::strategies::guard_map()) {
+            let parsed = crate::golden::parse(super::parser::input(), &input);
+            let initial_state = GuardState {
+                direction: parsed.guard_initial_direction,
+                current_position: parsed.guard_initial_position,
+            };
+            // Without an exit the patrol never ends, real inputs always have one.
+            proptest::prop_assume!(!move_guard_while_detecting_looping(
+                &parsed.map,
+                None,
+                initial_state
+            ));
+
+            let answer = super::solution(&input).unwrap();
+            proptest::prop_assert!(answer.part_1 >= 1usize.into());
+            proptest::prop_assert!(answer.part_2 < answer.part_1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    use super::Input;
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(6), "example")
+    }
+
+    pub fn intermediate() -> Input {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_p_1() -> usize {
+        41
+    }
+
+    pub fn output_p_2() -> usize {
+        6
+    }
+
+    #[test]
+    fn visualize() {
+        let visualize = |part| {
+            let mut frames = Vec::<String>::new();
+            let options = crate::viz::Options { part, color: false };
+            super::visualize(input(), &options, &mut frames).unwrap();
+            frames
+        };
+
+        let steps = visualize(1);
+        assert_eq!(
+            "....#.....\n\
+             .........#\n\
+             ..........\n\
+             ..#.......\n\
+             .......#..\n\
+             ..........\n\
+             .#..^.....\n\
+             ........#.\n\
+             #.........\n\
+             ......#...",
+            steps[0]
+        );
+        assert_eq!(
+            output_p_1(),
+            steps
+                .last()
+                .unwrap()
+                .chars()
+                .filter(|tile| "|-+v".contains(*tile))
+                .count()
+        );
+
+        let patrol = visualize(2);
+        assert_eq!(1, patrol.len());
+        assert_eq!(
+            output_p_2(),
+            patrol[0].chars().filter(|tile| *tile == 'O').count()
+        );
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
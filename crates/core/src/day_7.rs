@@ -0,0 +1,302 @@
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    incremental::Incremental,
+    phase,
+    validate::Report,
+};
+
+pub const TITLE: &str = "Bridge Repair";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(equations = input.len(), "parsed calibration equations");
+
+    Ok(Answer {
+        part_1: solution::sum_of_possible_calibration_results::<false>(&input).into(),
+        part_2: solution::sum_of_possible_calibration_results::<true>(&input).into(),
+    })
+}
+
+/// Parses the equations and notes how many numbers they have, as the
+/// operators to try grow exponentially with it.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let equations = parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!(
+        "{} equations of up to {} numbers",
+        equations.len(),
+        equations
+            .iter()
+            .map(|(_, nums)| nums.len())
+            .max()
+            .unwrap_or(0)
+    ));
+    Ok(report)
+}
+
+/// The answers from trying every way of putting operators between the
+/// numbers, which `--self-check` compares [`solution`] with.
+#[cfg(any(test, feature = "reference"))]
+pub fn reference_solution(input: &str) -> Result<Answer, SolveError> {
+    let input = parse(input)?;
+    Ok(Answer {
+        part_1: solution::sum_of_results_by_enumeration::<false>(&input).into(),
+        part_2: solution::sum_of_results_by_enumeration::<true>(&input).into(),
+    })
+}
+
+pub fn incremental_solution(
+    state: &mut Incremental<(i64, Vec<i64>)>,
+    input: &str,
+) -> Result<Answer, SolveError> {
+    let input = state.update(input, |line| parse_complete(parser::equation(), line))?;
+
+    Ok(Answer {
+        part_1: solution::sum_of_possible_calibration_results::<false>(input).into(),
+        part_2: solution::sum_of_possible_calibration_results::<true>(input).into(),
+    })
+}
+
+/// The equations as their test value and numbers.
+pub fn parse(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    parse_complete(parser::input(), input)
+}
+
+pub mod parser {
+    use crate::parse_util::{lines, spaced, Parser};
+
+    pub fn input<'a>() -> impl Parser<'a, Vec<(i64, Vec<i64>)>> {
+        lines(equation())
+    }
+
+    pub fn equation<'a>() -> impl Parser<'a, (i64, Vec<i64>)> {
+        nom::sequence::separated_pair(
+            nom::character::complete::i64,
+            nom::character::complete::char(':').and(nom::character::complete::space1),
+            spaced(nom::character::complete::i64),
+        )
+    }
+
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{lines, Rng, LINES};
+
+        let generated = lines(LINES, &mut Rng::new(7), |rng| {
+            let operands = (0..rng.between(3, 12))
+                .map(|_| rng.between(1, 999).to_string())
+                .collect::<Vec<_>>();
+            format!("{}: {}", rng.between(1, 1 << 40), operands.join(" "))
+        });
+        b.iter(|| input().parse(&generated).unwrap());
+    }
+}
+
+mod solution {
+    use guard::guard;
+
+    use crate::par::prelude::*;
+
+    /// Every result of putting operators between `nums`, the slow way the
+    /// search below is checked against. Results too big for an `i64` are left
+    /// out, they can't be a target.
+    #[cfg(any(test, feature = "reference"))]
+    fn all_expr_results<const DO_CONCAT: bool>(nums: &[i64]) -> Vec<i64> {
+        guard! {
+            let Some((head, remaining)) = uncons(nums) else {
+                return vec![]
+            }
+        }
+
+        let mut stack: Vec<(&[i64], i64)> = vec![(remaining, *head)];
+
+        let mut results = Vec::<i64>::new();
+
+        while let Some((remaining, current)) = stack.pop() {
+            if let Some((x, remaining)) = uncons(remaining) {
+                let shift = concat(1, *x) - x;
+                let concatenated = current.checked_mul(shift).and_then(|l| l.checked_add(*x));
+                let next = [
+                    current.checked_add(*x),
+                    current.checked_mul(*x),
+                    concatenated.filter(|_| DO_CONCAT),
+                ];
+                stack.extend(next.into_iter().flatten().map(|next| (remaining, next)));
+            } else {
+                results.push(current);
+            }
+        }
+
+        results
+    }
+
+    /// Works back from `target`, undoing the last operation for every
+    /// operator that could have produced it: addition as long as something is
+    /// left, multiplication only when it divides evenly and concatenation
+    /// only when `target` ends in the digits of the last number. Operands are
+    /// never negative, which is what makes the first check sound.
+    fn is_equation_possible<const DO_CONCAT: bool>(target: i64, nums: &[i64]) -> bool {
+        guard! {
+            let Some((last, nums)) = nums.split_last() else {
+                return false
+            }
+        }
+        if nums.is_empty() {
+            return target == *last;
+        }
+
+        if target >= *last && is_equation_possible::<DO_CONCAT>(target - last, nums) {
+            return true;
+        }
+        let could_multiply = match *last {
+            0 => target == 0,
+            last => target % last == 0 && is_equation_possible::<DO_CONCAT>(target / last, nums),
+        };
+        if could_multiply {
+            return true;
+        }
+        if DO_CONCAT {
+            let shift = concat(1, *last) - last;
+            return target >= *last
+                && (target - last) % shift == 0
+                && is_equation_possible::<DO_CONCAT>((target - last) / shift, nums);
+        }
+        false
+    }
+
+    #[cfg(any(test, feature = "reference"))]
+    pub fn sum_of_results_by_enumeration<const DO_CONCAT: bool>(input: &[(i64, Vec<i64>)]) -> i64 {
+        input
+            .iter()
+            .filter(|(target, nums)| all_expr_results::<DO_CONCAT>(nums).contains(target))
+            .map(|(target, _)| target)
+            .sum()
+    }
+
+    pub fn sum_of_possible_calibration_results<const DO_CONCAT: bool>(
+        input: &[(i64, Vec<i64>)],
+    ) -> i64 {
+        input
+            .par_iter()
+            .filter_map(|(target, nums)| {
+                is_equation_possible::<DO_CONCAT>(*target, nums).then_some(target)
+            })
+            .sum()
+    }
+
+    #[cfg(any(test, feature = "reference"))]
+    #[inline]
+    fn uncons<'a, T>(xs: &'a [T]) -> Option<(&'a T, &'a [T])> {
+        let x = xs.get(0)?;
+        let xs = &xs[1..];
+        Some((x, xs))
+    }
+
+    fn concat(l: i64, r: i64) -> i64 {
+        let mut exp = 1;
+
+        while r / 10i64.pow(exp) > 0 {
+            exp += 1
+        }
+
+        l * 10i64.pow(exp) + r
+    }
+
+    #[test]
+    fn example() {
+        let examples = super::example::intermediate();
+        assert_eq!(
+            super::example::output_p_1(),
+            sum_of_possible_calibration_results::<false>(&examples)
+        );
+        assert_eq!(
+            super::example::output_p_2(),
+            sum_of_possible_calibration_results::<true>(&examples)
+        );
+    }
+
+    #[test]
+    fn example_incremental() {
+        let input = super::example::input();
+        let mut state = super::Incremental::new();
+
+        for len in [0, 9, 26, input.len()] {
+            super::incremental_solution(&mut state, &input[..len]).unwrap();
+        }
+
+        let answer = super::incremental_solution(&mut state, input).unwrap();
+        assert_eq!(answer.part_1, super::example::output_p_1());
+        assert_eq!(answer.part_2, super::example::output_p_2());
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_concat(x: u16, y:u16) {
+            let using_format_parse: i64 = format!("{x}{y}").parse().unwrap();
+            proptest::prop_assert_eq!(using_format_parse, concat(x as i64, y as i64))
+        }
+
+        #[test]
+        fn prop_search_matches_enumeration(
+            nums in proptest::collection::vec(0i64..200, 1..7),
+            pick: proptest::sample::Index,
+        ) {
+            for concat in [false, true] {
+                let results = if concat {
+                    all_expr_results::<true>(&nums)
+                } else {
+                    all_expr_results::<false>(&nums)
+                };
+                let reachable = *pick.get(&results);
+                for target in [reachable, reachable + 1, reachable - 1] {
+                    let possible = if concat {
+                        is_equation_possible::<true>(target, &nums)
+                    } else {
+                        is_equation_possible::<false>(target, &nums)
+                    };
+                    proptest::prop_assert_eq!(results.contains(&target), possible);
+                }
+            }
+        }
+
+        #[test]
+        fn prop_matches_reference(input in crate::strategies::equations()) {
+            let answer = super::solution(&input).unwrap();
+            let reference = super::reference_solution(&input).unwrap();
+            proptest::prop_assert_eq!(
+                (reference.part_1, reference.part_2),
+                (answer.part_1, answer.part_2)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(7), "example")
+    }
+
+    pub fn intermediate() -> Vec<(i64, Vec<i64>)> {
+        crate::golden::parse(super::parser::input(), input())
+    }
+
+    pub fn output_p_1() -> i64 {
+        3749
+    }
+
+    pub fn output_p_2() -> i64 {
+        11387
+    }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
+}
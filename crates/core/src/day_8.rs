@@ -1,108 +1,124 @@
-use anyhow::anyhow;
-use nom::Parser;
-
 use std::collections::{BTreeMap, BTreeSet};
 
-#[derive(Debug)]
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    phase,
+    validate::{grid_shape, Report},
+};
+
+pub const TITLE: &str = "Resonant Collinearity";
+
+#[derive(Debug, serde::Serialize)]
 pub struct Answer {
-    pub part_1: usize,
-    pub part_2: usize,
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
 }
 
-pub fn solution<'a>(input: &'a str) -> anyhow::Result<Answer> {
-    let input = parser::input()
-        .parse(input)
-        .map_err(|err| anyhow!("failed to parse input: {}", err))?
-        .1;
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let input = phase::parsing(|| parse(input))?;
+    tracing::debug!(
+        size = ?input.grid_size,
+        frequencies = input.antennas_for_frequencies.len(),
+        "parsed antenna map"
+    );
 
     Ok(Answer {
-        part_1: solution::count_of_antinodes_p_1(&input),
-        part_2: solution::count_of_antinodes_p_2(&input),
+        part_1: solution::count_of_antinodes_p_1(&input).into(),
+        part_2: solution::count_of_antinodes_p_2(&input).into(),
     })
 }
 
+/// Checks that the map is rectangular, parses it and counts the antennas.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let mut report = Report::default();
+    if grid_shape(input, &mut report) {
+        let input = parse(input)?;
+        report.note(format_args!(
+            "{} antennas of {} frequencies",
+            input
+                .antennas_for_frequencies
+                .values()
+                .map(BTreeSet::len)
+                .sum::<usize>(),
+            input.antennas_for_frequencies.len()
+        ));
+    }
+    Ok(report)
+}
+
 #[derive(Debug, PartialEq, Eq)]
-struct Input {
-    grid_size: (usize, usize),
-    antennas_for_frequencies: BTreeMap<char, BTreeSet<(usize, usize)>>,
+pub struct Input {
+    /// Rows and columns of the map.
+    pub grid_size: (usize, usize),
+    /// The `(row, col)` of every antenna, by its frequency.
+    pub antennas_for_frequencies: BTreeMap<char, BTreeSet<(usize, usize)>>,
+}
+
+/// The antennas on the map.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_complete(parser::input(), input)
 }
 
-mod parser {
+pub mod parser {
     use std::{
         collections::{BTreeMap, BTreeSet},
         ops::Not,
     };
 
     use super::Input;
-
-    pub type ParserInput<'a> = &'a str;
-    pub type Error<'a> = nom::error::Error<ParserInput<'a>>;
-    pub trait Parser<'a, T> = nom::Parser<ParserInput<'a>, T, Error<'a>>;
+    use crate::{
+        grid::{Grid, GridSize},
+        parse_util::Parser,
+    };
 
     pub fn input<'a>() -> impl Parser<'a, Input> {
-        nom::combinator::map_res(grid(), grid_to_input)
+        Grid::parse(cell()).map(grid_to_input)
     }
 
-    fn grid_to_input(grid: Vec<Vec<Option<char>>>) -> Result<Input, String> {
-        let row_size = grid.len();
-        let col_size = grid.first().ok_or("empty grid".to_string())?.len();
-        let grid_size = (row_size, col_size);
-
-        let antennas_for_frequencies = grid
-            .into_iter()
-            .enumerate()
-            .map(
-                |(row_index, col)| -> Result<BTreeMap<char, BTreeSet<(usize, usize)>>, String> {
-                    if col.len() != col_size {
-                        return Err("ambiguous col size".to_string());
-                    }
-
-                    Ok(col.into_iter().enumerate().fold(
-                        BTreeMap::new(),
-                        |mut acc, (col_index, ch)| {
-                            if let Some(ch) = ch {
-                                acc.entry(ch).or_default().insert((row_index, col_index));
-                            }
-                            acc
-                        },
-                    ))
-                },
-            )
-            .collect::<Result<Vec<_>, String>>()?
-            .into_iter()
-            .fold(
-                BTreeMap::<char, BTreeSet<(usize, usize)>>::new(),
-                |acc, m| {
-                    m.into_iter().fold(acc, |mut acc, (ch, positions)| {
-                        acc.entry(ch).or_default().extend(positions.into_iter());
-                        acc
-                    })
-                },
-            );
+    fn grid_to_input(grid: Grid<Option<char>>) -> Input {
+        let GridSize(row_size, col_size) = grid.size();
+
+        let antennas_for_frequencies = grid.iter().fold(
+            BTreeMap::<char, BTreeSet<(usize, usize)>>::new(),
+            |mut acc, (position, ch)| {
+                if let Some(ch) = ch {
+                    acc.entry(*ch)
+                        .or_default()
+                        .insert((position.row_index, position.col_index));
+                }
+                acc
+            },
+        );
 
-        Ok(Input {
-            grid_size,
+        Input {
+            grid_size: (row_size, col_size),
             antennas_for_frequencies,
-        })
+        }
     }
 
-    fn grid<'a>() -> impl Parser<'a, Vec<Vec<Option<char>>>> {
-        nom::multi::separated_list1(nom::character::complete::newline, col())
-    }
-
-    fn col<'a>() -> impl Parser<'a, Vec<Option<char>>> {
-        nom::multi::many1(
-            nom::character::complete::satisfy(|ch| ch.is_alphanumeric() || ch == '.')
-                .map(|ch| (ch == '.').not().then_some(ch)),
-        )
+    fn cell<'a>() -> impl Parser<'a, Option<char>> {
+        nom::character::complete::satisfy(|ch| ch.is_ascii_alphanumeric() || ch == '.')
+            .map(|ch| (ch == '.').not().then_some(ch))
     }
 
-    #[test]
-    fn example() {
-        assert_eq!(
-            Ok(("", super::example::intermediate())),
-            input().parse(super::example::input())
+    #[bench]
+    fn large_input(b: &mut test::Bencher) {
+        use crate::generate::{grid, Rng};
+
+        let generated = grid(
+            &mut Rng::new(8),
+            |rng| {
+                if rng.below(50) == 0 {
+                    rng.pick(&['a', 'Z', '0', '7'])
+                } else {
+                    '.'
+                }
+            },
+            &[],
         );
+        b.iter(|| input().parse(&generated).unwrap());
     }
 }
 
@@ -265,14 +281,16 @@ mod solution {
 
 #[cfg(test)]
 mod example {
+    use crate::{day::Day, examples};
+
     use super::Input;
 
     pub fn input() -> &'static str {
-        include_str!("./examples/day8/example.txt")
+        examples::text(Day::new(8), "example")
     }
 
     pub fn intermediate() -> Input {
-        include!("./examples/day8/intermediate.in")
+        crate::golden::parse(super::parser::input(), input())
     }
 
     pub fn output_p_1() -> usize {
@@ -282,4 +300,6 @@ mod example {
     pub fn output_p_2() -> usize {
         34
     }
+
+    crate::golden::golden!(golden, input(), part_1: output_p_1(), part_2: output_p_2());
 }
@@ -0,0 +1,748 @@
+use std::iter::repeat;
+
+use itertools::Itertools;
+
+use crate::{
+    answer::AnswerValue,
+    error::{parse_complete, ParseError, SolveError},
+    phase,
+    validate::Report,
+    viz,
+};
+
+pub const TITLE: &str = "Disk Fragmenter";
+
+#[derive(Debug, serde::Serialize)]
+pub struct Answer {
+    pub part_1: AnswerValue,
+    pub part_2: AnswerValue,
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn solution<'a>(input: &'a str) -> Result<Answer, SolveError> {
+    let fragments = phase::parsing(|| parse(input))?;
+    tracing::debug!(fragments = fragments.len(), "parsed disk map");
+
+    Ok(Answer {
+        part_1: solution::part_1::compact_disk_and_calculate_checksum(fragments.as_slice()).into(),
+        part_2: solution::part_2::compact_disk_and_calculate_checksum(fragments.as_slice()).into(),
+    })
+}
+
+/// Checks that the disk map is all digits.
+pub fn validate(input: &str) -> Result<Report, SolveError> {
+    let digits = Digits::parse(input)?;
+    let mut report = Report::default();
+    report.note(format_args!(
+        "{} files and {} gaps",
+        digits.len().div_ceil(2),
+        digits.len() / 2
+    ));
+    Ok(report)
+}
+
+/// Like [`solution`], but reads the disk map straight from the input rather
+/// than parsing it into fragments first. Beyond the input itself, only the
+/// gaps part 2 fills files into take memory.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn streaming_solution(input: &str) -> Result<Answer, SolveError> {
+    let digits = phase::parsing(|| Digits::parse(input))?;
+
+    Ok(Answer {
+        part_1: solution::part_1::compact_disk_and_calculate_checksum(&digits).into(),
+        part_2: solution::part_2::compact_disk_and_calculate_checksum(&digits).into(),
+    })
+}
+
+/// The answers from moving the blocks of the disk around one by one, which
+/// `--self-check` compares [`solution`] with.
+#[cfg(any(test, feature = "reference"))]
+pub fn reference_solution(input: &str) -> Result<Answer, SolveError> {
+    let fragments = parse(input)?;
+    let blocks = fragments.iter().flat_map(Fragment::blocks).collect_vec();
+
+    let mut compacted_files = blocks.clone();
+    solution::part_2::compact_blocks(&mut compacted_files, fragments.len().div_ceil(2));
+    Ok(Answer {
+        part_1: solution::calculate_disk_checksum(&solution::part_1::compact_disk_with(
+            &blocks,
+            |_| {},
+        ))
+        .into(),
+        part_2: solution::calculate_disk_checksum(&compacted_files).into(),
+    })
+}
+
+/// Shows the disk after every move of the compactor in the puzzle's
+/// `00...111...2` notation, `options.part` picks block or whole file moves.
+pub fn visualize(
+    input: &str,
+    options: &viz::Options,
+    sink: &mut dyn viz::Sink,
+) -> Result<(), viz::Error> {
+    let mut result = Ok(());
+    let mut frame = |blocks: &mut dyn Iterator<Item = Block>| {
+        if result.is_ok() {
+            result = sink.frame(&render_blocks(blocks, options.color));
+        }
+    };
+
+    if options.part == 1 {
+        let blocks = parse_complete(parser::part1::input(), input)?;
+        solution::part_1::compact_disk_with(&blocks, |blocks| frame(&mut blocks.iter().copied()));
+    } else {
+        let fragments = parse(input)?;
+        let mut blocks = fragments.iter().flat_map(Fragment::blocks).collect_vec();
+        frame(&mut blocks.iter().copied());
+        solution::part_2::compact_files_with(fragments.as_slice(), |file_move| {
+            file_move.apply(&mut blocks);
+            frame(&mut blocks.iter().copied());
+        });
+    }
+
+    Ok(result?)
+}
+
+/// Draws free blocks as `.` and file blocks as the digits of their id, ids
+/// past 9 go on through `a-z` and `A-Z` and then start over. With `color`
+/// every file gets a color of its own to tell them apart anyway.
+fn render_blocks(blocks: impl Iterator<Item = Block>, color: bool) -> String {
+    const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    blocks
+        .map(|block| match block {
+            Block::Free => ".".to_owned(),
+            Block::File { id } => {
+                let digit = DIGITS[id % DIGITS.len()] as char;
+                if color {
+                    let style = viz::id_color(id).on_default();
+                    format!("{style}{digit}{style:#}")
+                } else {
+                    digit.to_string()
+                }
+            }
+        })
+        .collect()
+}
+
+/// A block of the disk, see [`Fragment::blocks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    Free,
+    File { id: usize },
+}
+
+/// A run of free blocks or of the blocks of a file, as a digit of the disk
+/// map says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fragment {
+    Free { size: usize },
+    File { id: usize, size: usize },
+}
+
+impl Fragment {
+    pub fn blocks(&self) -> impl Iterator<Item = Block> {
+        match *self {
+            Fragment::Free { size } => repeat(Block::Free).take(size),
+            Fragment::File { id, size } => repeat(Block::File { id }).take(size),
+        }
+    }
+}
+
+/// The fragments of a disk map in order, files at even and gaps at odd
+/// indices.
+trait DiskMap {
+    fn len(&self) -> usize;
+    fn fragment(&self, idx: usize) -> Fragment;
+}
+
+impl DiskMap for [Fragment] {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn fragment(&self, idx: usize) -> Fragment {
+        self[idx]
+    }
+}
+
+/// A disk map as the digits of the input, read as they are needed.
+#[derive(Debug, Clone, Copy)]
+struct Digits<'a>(&'a [u8]);
+
+impl<'a> Digits<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let digits = input.trim_end();
+        match digits.char_indices().find(|(_, ch)| !ch.is_ascii_digit()) {
+            Some((offset, ch)) => Err(ParseError::new(
+                input,
+                offset,
+                format!("expected a digit, found `{ch}`"),
+            )),
+            None if digits.is_empty() => Err(ParseError::new(
+                input,
+                0,
+                "expected a digit, found the end of the input".to_owned(),
+            )),
+            None => Ok(Self(digits.as_bytes())),
+        }
+    }
+}
+
+impl DiskMap for Digits<'_> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn fragment(&self, idx: usize) -> Fragment {
+        let size = usize::from(self.0[idx] - b'0');
+        match idx % 2 {
+            0 => Fragment::File { id: idx / 2, size },
+            _ => Fragment::Free { size },
+        }
+    }
+}
+
+/// The disk map as files and the gaps between them.
+pub fn parse(input: &str) -> Result<Vec<Fragment>, ParseError> {
+    parse_complete(parser::part2::input(), input)
+}
+
+pub mod parser {
+    use itertools::Itertools;
+
+    use crate::parse_util::{bytes, Parser};
+
+    struct FragmentState {
+        is_file: bool,
+        file_id: usize,
+    }
+
+    impl FragmentState {
+        fn advance(&mut self) {
+            if self.is_file {
+                self.file_id += 1
+            }
+            self.is_file = !self.is_file
+        }
+
+        fn initial_state() -> Self {
+            Self {
+                is_file: true,
+                file_id: 0,
+            }
+        }
+    }
+
+    fn input_from_digits<'a, T, F: Fn(Vec<usize>) -> T>(f: F) -> impl Parser<'a, T> {
+        bytes(nom::character::complete::digit1)
+            .map(|digits: &[u8]| {
+                digits
+                    .iter()
+                    .map(|digit| usize::from(digit - b'0'))
+                    .collect_vec()
+            })
+            .map(f)
+    }
+
+    pub mod part1 {
+        use std::iter::repeat;
+
+        use itertools::Itertools;
+
+        use super::{super::Block, input_from_digits, FragmentState, Parser};
+
+        pub fn input<'a>() -> impl Parser<'a, Vec<Block>> {
+            input_from_digits(digits_to_blocks)
+        }
+
+        fn digits_to_blocks(counts: Vec<usize>) -> Vec<Block> {
+            counts
+                .into_iter()
+                .scan(FragmentState::initial_state(), |state, count| {
+                    let block = if state.is_file {
+                        Block::File { id: state.file_id }
+                    } else {
+                        Block::Free
+                    };
+                    let blocks = repeat(block).take(count).collect_vec();
+                    state.advance();
+                    Some(blocks)
+                })
+                .flatten()
+                .collect_vec()
+        }
+
+        #[bench]
+        fn large_input(b: &mut test::Bencher) {
+            use crate::generate::{Rng, LINES};
+
+            let mut rng = Rng::new(91);
+            let generated = (0..LINES * 10)
+                .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                .collect::<String>();
+            b.iter(|| input().parse(&generated).unwrap());
+        }
+    }
+
+    pub mod part2 {
+        use itertools::Itertools;
+
+        use super::{super::Fragment, input_from_digits, FragmentState, Parser};
+
+        pub fn input<'a>() -> impl Parser<'a, Vec<Fragment>> {
+            input_from_digits(digits_to_fragments)
+        }
+
+        pub fn digits_to_fragments(counts: Vec<usize>) -> Vec<Fragment> {
+            counts
+                .into_iter()
+                .scan(FragmentState::initial_state(), |state, count| {
+                    let fragment = if state.is_file {
+                        Fragment::File {
+                            id: state.file_id,
+                            size: count,
+                        }
+                    } else {
+                        Fragment::Free { size: count }
+                    };
+                    state.advance();
+                    Some(fragment)
+                })
+                .collect_vec()
+        }
+
+        #[bench]
+        fn large_input(b: &mut test::Bencher) {
+            use crate::generate::{Rng, LINES};
+
+            let mut rng = Rng::new(92);
+            let generated = (0..LINES * 10)
+                .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                .collect::<String>();
+            b.iter(|| input().parse(&generated).unwrap());
+        }
+    }
+}
+
+mod solution {
+    #[cfg(any(test, feature = "reference"))]
+    use super::Block;
+
+    #[cfg(any(test, feature = "reference"))]
+    pub fn calculate_disk_checksum(blocks: &[Block]) -> u64 {
+        blocks
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (idx, block)| match block {
+                Block::File { id } => acc + idx as u64 * *id as u64,
+                Block::Free => acc,
+            })
+    }
+
+    pub mod part_1 {
+        use super::super::{Block, DiskMap, Fragment};
+
+        /// Moves single blocks from the end of the disk into the leftmost free
+        /// block until there are no gaps left, showing `on_step` the disk
+        /// before and after every move. Only used to visualize,
+        /// [`compact_disk_and_calculate_checksum`] gets the same result
+        /// without laying out the blocks.
+        pub fn compact_disk_with<F: FnMut(&[Block])>(
+            blocks: &[Block],
+            mut on_step: F,
+        ) -> Vec<Block> {
+            on_step(blocks);
+            if blocks.is_empty() {
+                return vec![];
+            }
+
+            let mut blocks = blocks.to_vec();
+            let indices = 0..blocks.len();
+
+            let mut l_iter = indices.clone().into_iter();
+            let mut r_iter = indices.rev();
+
+            loop {
+                let l_idx = l_iter.find(|idx| blocks[*idx] == Block::Free);
+                let r_idx = r_iter.find(|idx| blocks[*idx] != Block::Free);
+
+                match l_idx.zip(r_idx).filter(|(l_idx, r_idx)| l_idx < r_idx) {
+                    Some((l_idx, r_idx)) => {
+                        blocks.swap(l_idx, r_idx);
+                        on_step(&blocks);
+                    }
+                    None => break,
+                }
+            }
+
+            blocks
+        }
+
+        /// Adds up `id` times the position of each of `count` blocks laid out
+        /// one after the other.
+        struct Checksum {
+            sum: u64,
+            position: u64,
+        }
+
+        impl Checksum {
+            fn add(&mut self, id: usize, count: usize) {
+                let count = count as u64;
+                let positions = count * self.position + count * count.saturating_sub(1) / 2;
+                self.sum += id as u64 * positions;
+                self.position += count;
+            }
+        }
+
+        /// The last file before `end` and after `start` as its index, id and
+        /// size.
+        fn last_file_between<D: DiskMap + ?Sized>(
+            fragments: &D,
+            start: usize,
+            end: usize,
+        ) -> Option<(usize, usize, usize)> {
+            (start + 1..end)
+                .rev()
+                .find_map(|idx| match fragments.fragment(idx) {
+                    Fragment::File { id, size } => Some((idx, id, size)),
+                    Fragment::Free { .. } => None,
+                })
+        }
+
+        /// Walks the disk from the left, filling every gap with the blocks of
+        /// the rightmost file not yet moved, which a second index walks down
+        /// from the right. The two meet where the compacted disk ends.
+        pub fn compact_disk_and_calculate_checksum<D: DiskMap + ?Sized>(fragments: &D) -> u64 {
+            let mut checksum = Checksum {
+                sum: 0,
+                position: 0,
+            };
+            // The file being moved as its index, id and blocks not yet moved.
+            let mut right = (fragments.len(), 0, 0);
+
+            let mut left = 0;
+            while left < right.0 {
+                match fragments.fragment(left) {
+                    Fragment::File { id, size } => checksum.add(id, size),
+                    Fragment::Free { size: mut gap } => {
+                        while gap > 0 {
+                            if right.2 == 0 {
+                                match last_file_between(fragments, left, right.0) {
+                                    Some(file) => right = file,
+                                    None => {
+                                        right = (left, 0, 0);
+                                        break;
+                                    }
+                                }
+                            }
+                            let moved = gap.min(right.2);
+                            checksum.add(right.1, moved);
+                            gap -= moved;
+                            right.2 -= moved;
+                        }
+                    }
+                }
+                left += 1;
+            }
+            // Whatever is left of the file the walks met at stays in place.
+            checksum.add(right.1, right.2);
+
+            checksum.sum
+        }
+
+        #[test]
+        fn example() {
+            assert_eq!(
+                super::super::example::part_1::output(),
+                compact_disk_and_calculate_checksum(
+                    super::super::example::part_2::intermediate().as_slice()
+                )
+            )
+        }
+
+        #[test]
+        fn matches_block_by_block_compaction() {
+            use crate::generate::Rng;
+
+            use super::{super::parser::part2::digits_to_fragments, calculate_disk_checksum};
+
+            let mut rng = Rng::new(9);
+            for len in 0..200 {
+                let digits = (0..len).map(|_| rng.below(10)).collect::<Vec<_>>();
+                let fragments = digits_to_fragments(digits);
+                let blocks = fragments
+                    .iter()
+                    .flat_map(Fragment::blocks)
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    calculate_disk_checksum(&compact_disk_with(&blocks, |_| {})),
+                    compact_disk_and_calculate_checksum(fragments.as_slice()),
+                    "{fragments:?}"
+                );
+            }
+        }
+    }
+
+    pub mod part_2 {
+        use std::{cmp::Reverse, collections::BinaryHeap};
+
+        use super::super::{Block, DiskMap, Fragment};
+
+        /// A whole file moving from the block at `from` to the one at `to`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct FileMove {
+            pub id: usize,
+            pub size: usize,
+            pub from: usize,
+            pub to: usize,
+        }
+
+        impl FileMove {
+            pub fn apply(&self, blocks: &mut [Block]) {
+                blocks[self.from..self.from + self.size].fill(Block::Free);
+                blocks[self.to..self.to + self.size].fill(Block::File { id: self.id });
+            }
+        }
+
+        /// Moves every file once, highest id first, into the leftmost gap
+        /// before it that fits it, telling `on_move` about every move.
+        /// Returns the checksum of the compacted disk.
+        ///
+        /// The gaps are kept in one min-heap of starting blocks per gap size,
+        /// so the leftmost fitting gap is the smallest top among the heaps of
+        /// sizes at least the file's. A file only ever moves left, so the
+        /// space it leaves behind is never of use to the files after it.
+        pub fn compact_files_with<D: DiskMap + ?Sized, F: FnMut(FileMove)>(
+            fragments: &D,
+            mut on_move: F,
+        ) -> u64 {
+            let max_gap = (0..fragments.len())
+                .map(|idx| match fragments.fragment(idx) {
+                    Fragment::Free { size } => size,
+                    Fragment::File { .. } => 0,
+                })
+                .max()
+                .unwrap_or_default();
+            let mut gaps = vec![BinaryHeap::<Reverse<usize>>::new(); max_gap + 1];
+            let mut position = 0;
+            for idx in 0..fragments.len() {
+                match fragments.fragment(idx) {
+                    Fragment::Free { size } => {
+                        gaps[size].push(Reverse(position));
+                        position += size;
+                    }
+                    Fragment::File { size, .. } => position += size,
+                }
+            }
+
+            // Back to front, `position` is where the fragment at `idx` ends.
+            let mut checksum = 0;
+            for idx in (0..fragments.len()).rev() {
+                let (id, size) = match fragments.fragment(idx) {
+                    Fragment::File { id, size } => (id, size),
+                    Fragment::Free { size } => {
+                        position -= size;
+                        continue;
+                    }
+                };
+                position -= size;
+                let start = position;
+
+                let gap = gaps
+                    .iter()
+                    .enumerate()
+                    .skip(size.max(1))
+                    .filter_map(|(gap_size, starts)| Some((starts.peek()?.0, gap_size)))
+                    .filter(|(gap_start, _)| *gap_start < start)
+                    .min();
+
+                let start = match gap {
+                    Some((gap_start, gap_size)) => {
+                        gaps[gap_size].pop();
+                        if gap_size > size {
+                            gaps[gap_size - size].push(Reverse(gap_start + size));
+                        }
+                        on_move(FileMove {
+                            id,
+                            size,
+                            from: start,
+                            to: gap_start,
+                        });
+                        gap_start
+                    }
+                    None => start,
+                };
+                checksum += (start..start + size).sum::<usize>() as u64 * id as u64;
+            }
+
+            checksum
+        }
+
+        /// Moves the files like [`compact_files_with`], but finds every file
+        /// and gap in the blocks themselves, slowly.
+        #[cfg(any(test, feature = "reference"))]
+        pub fn compact_blocks(blocks: &mut [Block], files: usize) {
+            for id in (0..files).rev() {
+                let file = Block::File { id };
+                let Some(start) = blocks.iter().position(|block| *block == file) else {
+                    continue;
+                };
+                let size = blocks[start..]
+                    .iter()
+                    .take_while(|block| **block == file)
+                    .count();
+                if let Some(gap) = blocks[..start]
+                    .windows(size)
+                    .position(|window| window.iter().all(|block| *block == Block::Free))
+                {
+                    FileMove {
+                        id,
+                        size,
+                        from: start,
+                        to: gap,
+                    }
+                    .apply(blocks);
+                }
+            }
+        }
+
+        pub fn compact_disk_and_calculate_checksum<D: DiskMap + ?Sized>(fragments: &D) -> u64 {
+            let mut moves = 0usize;
+            let checksum = compact_files_with(fragments, |_| moves += 1);
+            tracing::debug!(moves, "moved files");
+            checksum
+        }
+
+        #[test]
+        fn example() {
+            assert_eq!(
+                super::super::example::part_2::output(),
+                compact_disk_and_calculate_checksum(
+                    super::super::example::part_2::intermediate().as_slice()
+                )
+            )
+        }
+
+        #[test]
+        fn matches_moving_blocks_around() {
+            use crate::generate::Rng;
+
+            use super::{super::parser::part2::digits_to_fragments, calculate_disk_checksum};
+
+            let mut rng = Rng::new(9);
+            for len in 0..200usize {
+                // Files are never empty, or the gaps around them would be
+                // one to the blocks but two to the fragments.
+                let digits = (0..len)
+                    .map(|idx| rng.below(10).max(1 - idx % 2))
+                    .collect::<Vec<_>>();
+                let fragments = digits_to_fragments(digits);
+                let mut blocks = fragments
+                    .iter()
+                    .flat_map(Fragment::blocks)
+                    .collect::<Vec<_>>();
+                compact_blocks(&mut blocks, len.div_ceil(2));
+                assert_eq!(
+                    calculate_disk_checksum(&blocks),
+                    compact_disk_and_calculate_checksum(fragments.as_slice()),
+                    "{fragments:?}"
+                );
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_solves_any_disk_map(input in crate::strategies::disk_map()) {
+            super::solution(&input).unwrap();
+        }
+
+        #[test]
+        fn prop_streaming_matches(input in crate::strategies::disk_map()) {
+            let answer = super::solution(&input).unwrap();
+            let streamed = super::streaming_solution(&input).unwrap();
+            proptest::prop_assert_eq!(
+                (answer.part_1, answer.part_2),
+                (streamed.part_1, streamed.part_2)
+            );
+        }
+
+        #[test]
+        fn prop_matches_reference(input in crate::strategies::disk_map()) {
+            let answer = super::solution(&input).unwrap();
+            let reference = super::reference_solution(&input).unwrap();
+            proptest::prop_assert_eq!(
+                (reference.part_1, reference.part_2),
+                (answer.part_1, answer.part_2)
+            );
+        }
+    }
+}
+
+#[test]
+fn visualization() {
+    let mut frames: Vec<String> = vec![];
+    let options = viz::Options {
+        part: 1,
+        color: false,
+    };
+    visualize("12345", &options, &mut frames).unwrap();
+    assert_eq!(
+        vec![
+            "0..111....22222",
+            "02.111....2222.",
+            "022111....222..",
+            "0221112...22...",
+            "02211122..2....",
+            "022111222......",
+        ],
+        frames
+    );
+
+    let mut frames: Vec<String> = vec![];
+    let options = viz::Options {
+        part: 2,
+        color: false,
+    };
+    visualize(example::input(), &options, &mut frames).unwrap();
+    assert_eq!(
+        vec![
+            "00...111...2...333.44.5555.6666.777.888899",
+            "0099.111...2...333.44.5555.6666.777.8888..",
+            "0099.1117772...333.44.5555.6666.....8888..",
+            "0099.111777244.333....5555.6666.....8888..",
+            "00992111777.44.333....5555.6666.....8888..",
+        ],
+        frames
+    );
+}
+
+#[cfg(test)]
+mod example {
+    use crate::{day::Day, examples};
+
+    pub fn input() -> &'static str {
+        examples::text(Day::new(9), "example")
+    }
+
+    pub mod part_1 {
+        pub fn output() -> u64 {
+            1928
+        }
+    }
+
+    pub mod part_2 {
+        use super::super::Fragment;
+
+        pub fn intermediate() -> Vec<Fragment> {
+            crate::golden::parse(super::super::parser::part2::input(), super::input())
+        }
+
+        pub fn output() -> u64 {
+            2858
+        }
+    }
+
+    crate::golden::golden!(golden, input(), part_1: part_1::output(), part_2: part_2::output());
+}
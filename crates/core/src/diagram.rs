@@ -0,0 +1,156 @@
+//! Grids as SVG diagrams that scale to any size, for write-ups: a square per
+//! cell with an optional label, with paths and borders drawn on top.
+
+use std::fmt::Write;
+
+use crate::grid::{Direction, Grid, GridSize, Position};
+
+/// Units per side of a cell, the diagram scales with its `viewBox` anyway.
+const CELL: usize = 10;
+
+/// How a cell of the grid is drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    /// Any CSS color.
+    pub fill: String,
+    pub label: Option<String>,
+}
+
+/// Lines along the sides of cells or through their centers, over the cells.
+#[derive(Debug, Clone)]
+pub struct Diagram {
+    size: GridSize,
+    cells: Vec<(Position, Cell)>,
+    overlays: Vec<String>,
+}
+
+impl Diagram {
+    pub fn new<T>(grid: &Grid<T>, mut cell: impl FnMut(&T) -> Cell) -> Self {
+        Self {
+            size: grid.size(),
+            cells: grid
+                .iter()
+                .map(|(position, value)| (position, cell(value)))
+                .collect(),
+            overlays: vec![],
+        }
+    }
+
+    /// Labels the cell at `position`, in place of the label it had.
+    pub fn label(&mut self, position: Position, label: &str) -> &mut Self {
+        let GridSize(_, cols) = self.size;
+        self.cells[position.row_index * cols + position.col_index]
+            .1
+            .label = Some(label.to_owned());
+        self
+    }
+
+    /// A line from the center of one cell to the next.
+    pub fn path(&mut self, positions: &[Position], color: &str) -> &mut Self {
+        let points = positions
+            .iter()
+            .map(|position| {
+                let (x, y) = center(*position);
+                format!("{x},{y}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.overlays.push(format!(
+            r#"<polyline points="{points}" fill="none" stroke="{color}" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"/>"#
+        ));
+        self
+    }
+
+    /// A line along the `side` of the cell at `position`.
+    pub fn border(&mut self, position: Position, side: Direction, color: &str) -> &mut Self {
+        let (left, top) = (position.col_index * CELL, position.row_index * CELL);
+        let (right, bottom) = (left + CELL, top + CELL);
+        let ((x1, y1), (x2, y2)) = match side {
+            Direction::Up => ((left, top), (right, top)),
+            Direction::Right => ((right, top), (right, bottom)),
+            Direction::Down => ((left, bottom), (right, bottom)),
+            Direction::Left => ((left, top), (left, bottom)),
+        };
+        self.overlays.push(format!(
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{color}" stroke-width="1.5" stroke-linecap="round"/>"#
+        ));
+        self
+    }
+
+    pub fn to_svg(&self) -> String {
+        let GridSize(rows, cols) = self.size;
+        let (width, height) = (cols * CELL, rows * CELL);
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{}" height="{}">"#,
+            width * 2,
+            height * 2
+        );
+        svg.push('\n');
+        for (position, cell) in &self.cells {
+            let (x, y) = (position.col_index * CELL, position.row_index * CELL);
+            writeln!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" fill="{}"/>"#,
+                cell.fill
+            )
+            .unwrap();
+        }
+        for (position, label) in self
+            .cells
+            .iter()
+            .filter_map(|(position, cell)| Some((position, cell.label.as_ref()?)))
+        {
+            let (x, y) = center(*position);
+            writeln!(
+                svg,
+                r#"<text x="{x}" y="{y}" font-family="monospace" font-size="6" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                escape(label)
+            )
+            .unwrap();
+        }
+        for overlay in &self.overlays {
+            svg.push_str(overlay);
+            svg.push('\n');
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn center(position: Position) -> (f64, f64) {
+    let half = CELL as f64 / 2.0;
+    (
+        (position.col_index * CELL) as f64 + half,
+        (position.row_index * CELL) as f64 + half,
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[test]
+fn drawing() {
+    let grid = Grid::new(vec![vec!['a', '<'], vec!['.', '.']]);
+    let mut diagram = Diagram::new(&grid, |ch| Cell {
+        fill: "white".to_owned(),
+        label: (*ch != '.').then(|| ch.to_string()),
+    });
+    diagram
+        .path(&[Position::new(0, 0), Position::new(1, 0)], "red")
+        .border(Position::new(1, 1), Direction::Right, "blue")
+        .label(Position::new(1, 0), "&");
+    let svg = diagram.to_svg();
+
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 20""#));
+    assert_eq!(4, svg.matches("<rect ").count());
+    assert!(svg.contains(r#"<rect x="10" y="10" width="10" height="10" fill="white"/>"#));
+    assert!(svg.contains(">a</text>"));
+    assert!(svg.contains(">&lt;</text>"));
+    assert!(svg.contains(r#"<text x="5" y="15" "#) && svg.contains(">&amp;</text>"));
+    assert!(svg.contains(r#"points="5,5 5,15""#));
+    assert!(svg.contains(r#"<line x1="20" y1="10" x2="20" y2="20""#));
+    assert!(svg.ends_with("</svg>\n"));
+}
@@ -0,0 +1,152 @@
+/// Sets of the elements `0..len`, starting out as one set each, that can be
+/// merged and asked which set an element is in. Union by rank and path
+/// compression keep both close to constant time.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// How many sets there are.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The element standing for the set `element` is in.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        // Path halving, every other node on the way up skips its parent.
+        while self.parents[element] != element {
+            let grandparent = self.parents[self.parents[element]];
+            self.parents[element] = grandparent;
+            element = grandparent;
+        }
+        element
+    }
+
+    /// Merges the sets of `a` and `b`, returns whether they were apart.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many elements the set of `element` has.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Every set as its elements in increasing order, sets ordered by their
+    /// smallest element.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of_root = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if group_of_root[root] == usize::MAX {
+                group_of_root[root] = groups.len();
+                groups.push(vec![]);
+            }
+            groups[group_of_root[root]].push(element);
+        }
+        groups
+    }
+}
+
+#[test]
+fn union_and_find() {
+    let mut set = DisjointSet::new(6);
+    assert_eq!(6, set.sets());
+
+    assert!(set.union(0, 1));
+    assert!(set.union(2, 3));
+    assert!(set.union(1, 3));
+    assert!(!set.union(0, 2));
+
+    assert_eq!(3, set.sets());
+    assert!(set.same_set(0, 3));
+    assert!(!set.same_set(0, 4));
+    assert_eq!(4, set.set_size(2));
+    assert_eq!(1, set.set_size(5));
+    assert_eq!(vec![vec![0, 1, 2, 3], vec![4], vec![5]], set.groups());
+}
+
+#[test]
+fn long_chain() {
+    let len = 10_000;
+    let mut set = DisjointSet::new(len);
+    for element in 1..len {
+        set.union(element - 1, element);
+    }
+
+    assert_eq!(1, set.sets());
+    assert_eq!(len, set.set_size(0));
+    assert!(set.same_set(0, len - 1));
+}
+
+#[test]
+fn grid_regions() {
+    use crate::grid::{Grid, GridSize, Position};
+
+    let grid = Grid::new(vec![
+        "aab".chars().collect(),
+        "abb".chars().collect(),
+        "aba".chars().collect(),
+    ]);
+    let GridSize(_, cols) = grid.size();
+    let index = |position: Position| position.row_index * cols + position.col_index;
+
+    let mut regions = DisjointSet::new(grid.positions().count());
+    for (position, cell) in grid.iter() {
+        for (neighbor, _) in grid
+            .neighbor_cells4(position)
+            .filter(|(_, neighbor)| *neighbor == cell)
+        {
+            regions.union(index(position), index(neighbor));
+        }
+    }
+
+    assert_eq!(3, regions.sets());
+    assert_eq!(
+        vec![vec![0, 1, 3, 6], vec![2, 4, 5, 7], vec![8]],
+        regions.groups()
+    );
+}
@@ -0,0 +1,223 @@
+use itertools::Itertools;
+
+/// Where and why parsing a puzzle input failed.
+///
+/// `line` and `column` are 1-based, `column` counts chars rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("failed to parse input at line {line}, column {column}: {message}")]
+pub struct ParseError {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(input: &str, offset: usize, message: String) -> Self {
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let column = consumed[line_start..].chars().count() + 1;
+
+        Self {
+            offset,
+            line,
+            column,
+            message,
+        }
+    }
+
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => {
+                Self::new(input, input.len(), "unexpected end of input".to_owned())
+            }
+            nom::Err::Error(err) | nom::Err::Failure(err) => Self::new(
+                input,
+                input.len() - err.input.len(),
+                format!("{}, found {}", expectation(err.code), found(err.input)),
+            ),
+        }
+    }
+
+    /// The line of `input` the error points into.
+    pub fn line_text<'a>(&self, input: &'a str) -> &'a str {
+        input.lines().nth(self.line - 1).unwrap_or("")
+    }
+}
+
+/// What the parser that failed with `kind` was looking for, in words.
+fn expectation(kind: nom::error::ErrorKind) -> String {
+    use nom::error::ErrorKind;
+
+    match kind {
+        ErrorKind::Digit | ErrorKind::HexDigit | ErrorKind::OctDigit => {
+            "expected a digit".to_owned()
+        }
+        ErrorKind::Alpha => "expected a letter".to_owned(),
+        ErrorKind::AlphaNumeric => "expected a letter or digit".to_owned(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace".to_owned(),
+        ErrorKind::Char | ErrorKind::Tag | ErrorKind::OneOf | ErrorKind::Satisfy => {
+            "unexpected character".to_owned()
+        }
+        ErrorKind::CrLf => "expected a line break".to_owned(),
+        ErrorKind::Eof => "expected the end of the input".to_owned(),
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "invalid value".to_owned(),
+        kind => format!("expected {}", kind.description().to_lowercase()),
+    }
+}
+
+/// The start of what's left of the input, as in "found `x`".
+fn found(rest: &str) -> String {
+    match rest.chars().next() {
+        None => "the end of the input".to_owned(),
+        Some('\n') => "the end of the line".to_owned(),
+        // Inputs are ASCII, see `parse_util`. Anything else is hard to tell
+        // from what it looks like, or to see at all.
+        Some(ch) if !ch.is_ascii() => {
+            format!("the non-ASCII character `{ch}` (U+{:04X})", u32::from(ch))
+        }
+        Some(ch) => format!("`{ch}`"),
+    }
+}
+
+/// Runs `parser` on `input` and makes sure nothing but whitespace, like the
+/// newline a downloaded input ends with, is left over. Anything else means the
+/// parser stopped early, most likely at a malformed line, and solving what it
+/// got so far would give a wrong answer.
+pub fn parse_complete<'a, T>(
+    mut parser: impl nom::Parser<&'a str, T, nom::error::Error<&'a str>>,
+    input: &'a str,
+) -> Result<T, ParseError> {
+    let (rest, parsed) = parser
+        .parse(input)
+        .map_err(|err| ParseError::from_nom(input, err))?;
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        return Ok(parsed);
+    }
+
+    // Inputs are mostly made of lines alike, so what the parser makes of
+    // the first line it left over usually points at what's wrong with it.
+    let line = rest.lines().next().unwrap_or(rest);
+    let err = match parser.parse(line) {
+        Err(err) => ParseError::from_nom(line, err),
+        Ok((line_rest, _)) => match line_rest.trim_start() {
+            "" => ParseError::new(line, 0, format!("unexpected trailing input `{line}`")),
+            line_rest => ParseError::new(
+                line,
+                line.len() - line_rest.len(),
+                format!("unexpected trailing input `{line_rest}`"),
+            ),
+        },
+    };
+    Err(ParseError::new(
+        input,
+        input.len() - rest.len() + err.offset,
+        err.message,
+    ))
+}
+
+/// Why a day came up without an answer. Apart from [`SolveError::Parse`] the
+/// input is well-formed, the puzzle just has no answer for it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SolveError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    /// Day 5: the ordering rules for an update go round in a circle.
+    #[error(
+        "the rules for update {update:?} form a cycle {}",
+        .cycle.iter().chain(.cycle.first()).join(" -> ")
+    )]
+    RuleCycle { update: Vec<i64>, cycle: Vec<i64> },
+    /// Day 5: the ordering rules leave the order of some pages open.
+    #[error("the rules don't decide the order of every page in update {update:?}")]
+    UndecidedOrder { update: Vec<i64> },
+    /// Day 13.
+    #[error(transparent)]
+    NumericOverflow(#[from] crate::day_13::NumericOverflow),
+    /// Day 14: the robots never all stand on tiles of their own.
+    #[error("the robots never stop overlapping")]
+    RobotsOverlap,
+    /// Day 16: there's no way through the maze.
+    #[error("unable to reach the ending cell")]
+    Unreachable,
+}
+
+#[test]
+fn location() {
+    let input = "12\n3x4\n";
+    let err = ParseError::new(input, 4, "Digit".to_owned());
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.line_text(input), "3x4");
+
+    let err = ParseError::new(input, 0, "Digit".to_owned());
+    assert_eq!((err.line, err.column), (1, 1));
+}
+
+#[test]
+fn trailing_input() {
+    let number = || nom::character::complete::u32::<_, nom::error::Error<_>>;
+    assert_eq!(Ok(12), parse_complete(number(), "12\n"));
+
+    let err = parse_complete(number(), "12\n3x4\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!("unexpected trailing input `x4`", err.message);
+
+    let numbers = || {
+        nom::multi::separated_list1(
+            nom::character::complete::newline::<_, nom::error::Error<_>>,
+            nom::character::complete::u32,
+        )
+    };
+    let err = parse_complete(numbers(), "12\n3\nx4\n").unwrap_err();
+    assert_eq!((err.line, err.column), (3, 1));
+    assert_eq!("expected a digit, found `x`", err.message);
+}
+
+#[test]
+fn rule_cycles() {
+    let err = SolveError::RuleCycle {
+        update: vec![1, 2, 3],
+        cycle: vec![1, 3],
+    };
+    assert_eq!(
+        "the rules for update [1, 2, 3] form a cycle 1 -> 3 -> 1",
+        err.to_string()
+    );
+
+    let err = SolveError::RuleCycle {
+        update: vec![1],
+        cycle: vec![],
+    };
+    assert_eq!("the rules for update [1] form a cycle ", err.to_string());
+}
+
+#[test]
+fn nom_errors() {
+    let err = ParseError::from_nom(
+        "12\n3x4",
+        nom::Err::Error(nom::error::Error::new("x4", nom::error::ErrorKind::Digit)),
+    );
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!("expected a digit, found `x`", err.message);
+
+    let err = ParseError::from_nom(
+        "12\n",
+        nom::Err::Error(nom::error::Error::new("", nom::error::ErrorKind::Digit)),
+    );
+    assert_eq!("expected a digit, found the end of the input", err.message);
+
+    let err = ParseError::from_nom(
+        "1\u{200b}2",
+        nom::Err::Error(nom::error::Error::new(
+            "\u{200b}2",
+            nom::error::ErrorKind::Digit,
+        )),
+    );
+    assert_eq!((err.line, err.column), (1, 2));
+    assert_eq!(
+        "expected a digit, found the non-ASCII character `\u{200b}` (U+200B)",
+        err.message
+    );
+}
@@ -0,0 +1,36 @@
+//! The examples from the puzzle texts, embedded at build time from
+//! `src/examples/dayN/<variant>.txt`. A day with a single example calls it
+//! `example`, one with several numbers them, as in `example.1`.
+
+use crate::day::Day;
+
+/// Every example as `(day, variant, text)`, ordered by day and variant.
+const EXAMPLES: &[(u8, &str, &str)] = include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+/// The example `variant` of `day`. Panics if there's no such file, the
+/// examples ship with the crate so a missing one is a bug.
+pub fn text(day: Day, variant: &str) -> &'static str {
+    all(day)
+        .find(|&(name, _)| name == variant)
+        .map(|(_, text)| text)
+        .unwrap_or_else(|| panic!("there's no src/examples/{day}/{variant}.txt"))
+}
+
+/// Every example of `day` as `(variant, text)`.
+pub fn all(day: Day) -> impl Iterator<Item = (&'static str, &'static str)> {
+    EXAMPLES
+        .iter()
+        .filter(move |&&(number, _, _)| number == day.number())
+        .map(|&(_, variant, text)| (variant, text))
+}
+
+#[test]
+fn variants() {
+    assert_eq!(
+        vec!["example.1", "example.2"],
+        all(Day::new(3))
+            .map(|(variant, _)| variant)
+            .collect::<Vec<_>>()
+    );
+    assert!(text(Day::new(1), "example").starts_with("3   4"));
+}
@@ -0,0 +1,169 @@
+//! A C interface with the `ffi` feature, to time the solutions against ones
+//! in other languages. `include/aoc2024.h` declares it. Build a shared
+//! library with
+//!
+//! ```sh
+//! cargo rustc -p aoc-2024-core --release --features ffi --crate-type cdylib
+//! ```
+
+use std::{
+    ffi::c_int,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use crate::{day::Day, error::SolveError, registry};
+
+/// What [`aoc2024_solve`] returns, `AOC2024_*` in the header.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    /// `input` or `out_buf` is null.
+    NullPointer = 1,
+    /// `day` isn't within 1 to 25.
+    InvalidDay = 2,
+    NotImplemented = 3,
+    /// The day has no such part.
+    InvalidPart = 4,
+    /// The input isn't UTF-8.
+    InvalidUtf8 = 5,
+    /// The input doesn't parse.
+    InvalidInput = 6,
+    // 7 was for any input without a solution, which now each have their own
+    // status from 10 on.
+    /// The answer and its NUL don't fit into `out_buf`.
+    BufferTooSmall = 8,
+    /// The solver panicked, which is a bug.
+    Panic = 9,
+    /// Day 5: the ordering rules for an update go round in a circle.
+    RuleCycle = 10,
+    /// Day 5: the ordering rules leave the order of some pages open.
+    UndecidedOrder = 11,
+    /// Day 13: the numbers get too large to work with.
+    NumericOverflow = 12,
+    /// Day 14: the robots never all stand on tiles of their own.
+    RobotsOverlap = 13,
+    /// Day 16: there's no way through the maze.
+    Unreachable = 14,
+}
+
+impl From<&SolveError> for Status {
+    fn from(err: &SolveError) -> Self {
+        match err {
+            SolveError::Parse(_) => Status::InvalidInput,
+            SolveError::RuleCycle { .. } => Status::RuleCycle,
+            SolveError::UndecidedOrder { .. } => Status::UndecidedOrder,
+            SolveError::NumericOverflow(_) => Status::NumericOverflow,
+            SolveError::RobotsOverlap => Status::RobotsOverlap,
+            SolveError::Unreachable => Status::Unreachable,
+        }
+    }
+}
+
+/// Solves part `part` of `day` for the `len` bytes at `input` and writes the
+/// answer into `out_buf` as NUL-terminated text.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes and `out_buf` to `out_len`
+/// writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn aoc2024_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> c_int {
+    if input.is_null() || out_buf.is_null() {
+        return Status::NullPointer as c_int;
+    }
+    let input = slice::from_raw_parts(input, len);
+    let out = slice::from_raw_parts_mut(out_buf, out_len);
+    // `out` is only written to once the answer is there, a panic leaves it
+    // as it was.
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input, out))) {
+        Ok(Ok(())) => Status::Ok as c_int,
+        Ok(Err(status)) => status as c_int,
+        Err(_) => Status::Panic as c_int,
+    }
+}
+
+fn solve(day: u8, part: u8, input: &[u8], out: &mut [u8]) -> Result<(), Status> {
+    let day = Day::try_from(day).map_err(|_| Status::InvalidDay)?;
+    let solver = registry::solver(day).ok_or(Status::NotImplemented)?;
+    let input = std::str::from_utf8(input).map_err(|_| Status::InvalidUtf8)?;
+    let answer = solver.solve(input).map_err(|err| Status::from(&err))?;
+    let answer = match answer.get(format!("part_{part}")) {
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+        None => return Err(Status::InvalidPart),
+    };
+
+    let out = out
+        .get_mut(..answer.len() + 1)
+        .ok_or(Status::BufferTooSmall)?;
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+    Ok(())
+}
+
+#[test]
+fn solving() {
+    let solve = |day, part, input: &[u8], out: &mut [u8]| unsafe {
+        aoc2024_solve(
+            day,
+            part,
+            input.as_ptr(),
+            input.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    let input = b"3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+    let mut out = [0xff; 8];
+    assert_eq!(Status::Ok as c_int, solve(1, 2, input, &mut out));
+    assert_eq!(b"31\0", &out[..3]);
+
+    let mut out = [0; 2];
+    assert_eq!(
+        Status::BufferTooSmall as c_int,
+        solve(1, 2, input, &mut out)
+    );
+    let mut out = [0; 8];
+    assert_eq!(Status::InvalidPart as c_int, solve(1, 3, input, &mut out));
+    assert_eq!(Status::InvalidDay as c_int, solve(26, 1, input, &mut out));
+    assert_eq!(
+        Status::NotImplemented as c_int,
+        solve(25, 1, input, &mut out)
+    );
+    assert_eq!(Status::InvalidUtf8 as c_int, solve(1, 1, b"\xff", &mut out));
+    assert_eq!(Status::InvalidInput as c_int, solve(1, 1, b"3 x", &mut out));
+    assert_eq!(
+        Status::RuleCycle as c_int,
+        solve(5, 2, b"1|2\n2|3\n3|1\n\n3,2,1", &mut out)
+    );
+    assert_eq!(
+        Status::NumericOverflow as c_int,
+        solve(
+            13,
+            1,
+            format!(
+                "Button A: X+{0}, Y+1\nButton B: X+1, Y+{0}\nPrize: X=1, Y=1",
+                i128::MAX
+            )
+            .as_bytes(),
+            &mut out
+        )
+    );
+    assert_eq!(
+        Status::Unreachable as c_int,
+        solve(16, 1, b"#####\n#S#E#\n#####", &mut out)
+    );
+    assert_eq!(Status::NullPointer as c_int, unsafe {
+        aoc2024_solve(1, 1, std::ptr::null(), 0, out.as_mut_ptr(), out.len())
+    });
+}
@@ -0,0 +1,76 @@
+//! Large pseudo-random puzzle inputs for the parser benchmarks, far bigger
+//! than the real ones so parsing dominates. Run them with
+//! `cargo bench --lib parser`.
+
+/// Lines in generated line-based inputs.
+pub const LINES: usize = 20_000;
+
+/// Rows and columns of generated grids.
+pub const GRID_SIZE: usize = 500;
+
+/// xorshift64, reproducible and good enough for filler.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Uniform in `low..=high`.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    pub fn pick<T: Copy>(&mut self, choices: &[T]) -> T {
+        choices[self.below(choices.len())]
+    }
+}
+
+/// `count` lines made by `line`, each ending in a newline.
+pub fn lines(count: usize, rng: &mut Rng, mut line: impl FnMut(&mut Rng) -> String) -> String {
+    (0..count).map(|_| line(rng) + "\n").collect()
+}
+
+/// A [`GRID_SIZE`] square grid of cells made by `cell`, where each of
+/// `unique` shows up exactly once in place of a cell.
+pub fn grid(rng: &mut Rng, mut cell: impl FnMut(&mut Rng) -> char, unique: &[char]) -> String {
+    let mut cells: Vec<Vec<char>> = (0..GRID_SIZE)
+        .map(|_| (0..GRID_SIZE).map(|_| cell(rng)).collect())
+        .collect();
+    for (offset, ch) in unique.iter().enumerate() {
+        // Spread out along a diagonal so they never land on each other.
+        let index = (rng.below(GRID_SIZE / unique.len()) + offset * GRID_SIZE / unique.len())
+            .min(GRID_SIZE - 1);
+        cells[index][index] = *ch;
+    }
+
+    cells
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn deterministic() {
+    let make = || lines(3, &mut Rng::new(7), |rng| rng.between(-5, 5).to_string());
+    assert_eq!(make(), make());
+    assert_eq!(3, make().lines().count());
+
+    let grid = grid(&mut Rng::new(7), |_| '.', &['S', 'E']);
+    assert_eq!(GRID_SIZE, grid.lines().count());
+    assert_eq!(1, grid.matches('S').count());
+    assert_eq!(1, grid.matches('E').count());
+}
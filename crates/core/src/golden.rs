@@ -0,0 +1,31 @@
+//! Examples from the puzzle texts, checked end to end. A day's `example`
+//! module only needs the example's text and the answers the puzzle gives for
+//! it; the inputs its other tests work on come from parsing that text.
+
+/// What `parser` makes of `input`, which it has to consume entirely.
+pub fn parse<'a, T>(
+    mut parser: impl nom::Parser<&'a str, T, nom::error::Error<&'a str>>,
+    input: &'a str,
+) -> T {
+    let (rest, parsed) = parser.parse(input).expect("the example should parse");
+    assert_eq!("", rest, "the parser should consume the whole example");
+    parsed
+}
+
+/// Declares a test that solves an example with the day's `solution` and
+/// compares parts of the answer with the puzzle's.
+///
+/// ```ignore
+/// golden!(example, input(), part_1: 11, part_2: 31);
+/// ```
+macro_rules! golden {
+    ($name:ident, $input:expr, $($part:ident: $expected:expr),+ $(,)?) => {
+        #[test]
+        fn $name() {
+            let answer = super::solution($input).unwrap();
+            $(assert_eq!($expected, answer.$part, stringify!($part));)+
+        }
+    };
+}
+
+pub(crate) use golden;
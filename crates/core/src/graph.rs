@@ -0,0 +1,1008 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap, HashMap},
+    fmt::{self, Debug, Display},
+    hash::Hash,
+    ops::Add,
+};
+
+use itertools::Itertools;
+
+use crate::pathfinding::dijkstra;
+
+/// A directed graph. Nodes are kept in the order they were first seen, which
+/// is also the order every query returns them in.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            indices: HashMap::new(),
+            successors: vec![],
+            predecessors: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for Graph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        edges
+            .into_iter()
+            .fold(Self::default(), |mut graph, (src, dest)| {
+                graph.add_edge(src, dest);
+                graph
+            })
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_edges<'a>(edges: impl IntoIterator<Item = &'a (N, N)>) -> Self
+    where
+        N: 'a,
+    {
+        edges.into_iter().cloned().collect()
+    }
+
+    fn add_node_index(&mut self, node: N) -> usize {
+        if let Some(index) = self.indices.get(&node) {
+            return *index;
+        }
+        self.indices.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.successors.push(vec![]);
+        self.predecessors.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.add_node_index(node);
+    }
+
+    pub fn add_edge(&mut self, src: N, dest: N) {
+        let src = self.add_node_index(src);
+        let dest = self.add_node_index(dest);
+        if !self.successors[src].contains(&dest) {
+            self.successors[src].push(dest);
+            self.predecessors[dest].push(src);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn has_edge(&self, src: &N, dest: &N) -> bool {
+        match (self.indices.get(src), self.indices.get(dest)) {
+            (Some(src), Some(dest)) => self.successors[*src].contains(dest),
+            _ => false,
+        }
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.successors
+            .iter()
+            .enumerate()
+            .flat_map(move |(src, dests)| {
+                dests
+                    .iter()
+                    .map(move |dest| (&self.nodes[src], &self.nodes[*dest]))
+            })
+    }
+
+    /// Nodes `node` has an edge to, nothing if it isn't in the graph.
+    pub fn successors<'a>(&'a self, node: &N) -> impl 'a + Iterator<Item = &'a N> {
+        self.adjacent(&self.successors, node)
+    }
+
+    /// Nodes with an edge to `node`, nothing if it isn't in the graph.
+    pub fn predecessors<'a>(&'a self, node: &N) -> impl 'a + Iterator<Item = &'a N> {
+        self.adjacent(&self.predecessors, node)
+    }
+
+    fn adjacent<'a>(
+        &'a self,
+        adjacency: &'a [Vec<usize>],
+        node: &N,
+    ) -> impl 'a + Iterator<Item = &'a N> {
+        self.indices
+            .get(node)
+            .map(|index| adjacency[*index].as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|index| &self.nodes[*index])
+    }
+
+    /// Orders the nodes so that every edge points forward.
+    pub fn topological_sort(&self) -> Result<Vec<N>, CycleError<N>> {
+        self.subgraph(self.nodes()).topological_sort()
+    }
+
+    /// Every clique no node can be added to, see
+    /// [`SubgraphView::maximal_cliques`].
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        self.subgraph(self.nodes()).maximal_cliques()
+    }
+
+    /// A largest clique, see [`SubgraphView::maximum_clique`].
+    pub fn maximum_clique(&self) -> Vec<N> {
+        self.subgraph(self.nodes()).maximum_clique()
+    }
+
+    /// Nodes forming a cycle, see [`SubgraphView::find_cycle`].
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        self.subgraph(self.nodes()).find_cycle()
+    }
+
+    /// Groups of nodes that can all reach each other, see
+    /// [`SubgraphView::strongly_connected_components`].
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        self.subgraph(self.nodes()).strongly_connected_components()
+    }
+
+    /// [`SubgraphView::to_dot`].
+    pub fn to_dot(&self, edge_attributes: impl FnMut(&N, &N) -> Option<String>) -> String
+    where
+        N: Display,
+    {
+        self.subgraph(self.nodes()).to_dot(edge_attributes)
+    }
+
+    /// The graph restricted to `nodes`, those not in the graph are ignored.
+    pub fn subgraph<'a, 'b>(&'a self, nodes: impl IntoIterator<Item = &'b N>) -> SubgraphView<'a, N>
+    where
+        N: 'b,
+    {
+        let mut members = vec![false; self.nodes.len()];
+        for node in nodes {
+            if let Some(index) = self.indices.get(node) {
+                members[*index] = true;
+            }
+        }
+        SubgraphView {
+            graph: self,
+            members,
+        }
+    }
+}
+
+/// Borrows a [`Graph`] but only sees some of its nodes and the edges between
+/// them.
+#[derive(Debug, Clone)]
+pub struct SubgraphView<'a, N> {
+    graph: &'a Graph<N>,
+    members: Vec<bool>,
+}
+
+impl<'a, N: Clone + Eq + Hash> SubgraphView<'a, N> {
+    fn member_indices(&self) -> impl '_ + Iterator<Item = usize> {
+        self.members
+            .iter()
+            .enumerate()
+            .filter_map(|(index, member)| member.then_some(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.member_indices().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.member_indices().next().is_none()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.graph
+            .indices
+            .get(node)
+            .is_some_and(|index| self.members[*index])
+    }
+
+    pub fn has_edge(&self, src: &N, dest: &N) -> bool {
+        self.contains(src) && self.contains(dest) && self.graph.has_edge(src, dest)
+    }
+
+    pub fn nodes(&self) -> impl '_ + Iterator<Item = &'a N> {
+        let graph = self.graph;
+        self.member_indices().map(move |index| &graph.nodes[index])
+    }
+
+    pub fn successors(&self, node: &N) -> impl '_ + Iterator<Item = &'a N> {
+        let graph = self.graph;
+        graph
+            .successors(node)
+            .filter(|successor| self.contains(successor))
+    }
+
+    /// Orders the nodes so that every edge points forward.
+    pub fn topological_sort(&self) -> Result<Vec<N>, CycleError<N>> {
+        let mut result = Vec::with_capacity(self.members.len());
+        let mut marked = vec![false; self.members.len()];
+        let mut tmp_marked = vec![false; self.members.len()];
+
+        for index in self.member_indices() {
+            self.visit(&mut result, &mut marked, &mut tmp_marked, index)?;
+        }
+
+        result.reverse();
+        Ok(result
+            .into_iter()
+            .map(|index| self.graph.nodes[index].clone())
+            .collect())
+    }
+
+    fn visit(
+        &self,
+        result: &mut Vec<usize>,
+        marked: &mut [bool],
+        tmp_marked: &mut [bool],
+        index: usize,
+    ) -> Result<(), CycleError<N>> {
+        if marked[index] {
+            return Ok(());
+        }
+        if tmp_marked[index] {
+            return Err(CycleError {
+                node: self.graph.nodes[index].clone(),
+            });
+        }
+
+        tmp_marked[index] = true;
+
+        self.graph.successors[index]
+            .iter()
+            .filter(|successor| self.members[**successor])
+            .try_for_each(|successor| self.visit(result, marked, tmp_marked, *successor))?;
+
+        marked[index] = true;
+        result.push(index);
+
+        Ok(())
+    }
+
+    /// The graph in Graphviz's DOT language, every node named after how it's
+    /// displayed. `edge_attributes` may give an edge attributes like
+    /// `color=red`.
+    pub fn to_dot(&self, mut edge_attributes: impl FnMut(&N, &N) -> Option<String>) -> String
+    where
+        N: Display,
+    {
+        let quote = |node: &N| format!("{:?}", node.to_string());
+        let mut dot = "digraph {\n".to_owned();
+        for node in self.nodes() {
+            dot += &format!("    {};\n", quote(node));
+        }
+        for src in self.nodes() {
+            for dest in self.successors(src) {
+                let attributes = edge_attributes(src, dest)
+                    .map(|attributes| format!(" [{attributes}]"))
+                    .unwrap_or_default();
+                dot += &format!("    {} -> {}{attributes};\n", quote(src), quote(dest));
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+
+    /// Nodes `[a, b, .., z]` with edges `a -> b -> .. -> z -> a`, or `None`
+    /// if the graph is acyclic. A self loop is a cycle of one node.
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Mark {
+            Unvisited,
+            OnPath,
+            Done,
+        }
+
+        let mut marks = vec![Mark::Unvisited; self.members.len()];
+        for root in self.member_indices() {
+            if marks[root] != Mark::Unvisited {
+                continue;
+            }
+
+            // The current path of the search, each node with how many of its
+            // successors were looked at.
+            marks[root] = Mark::OnPath;
+            let mut path = vec![(root, 0)];
+            while let Some((index, visited_successors)) = path.last_mut() {
+                let Some(successor) = self.graph.successors[*index]
+                    .get(*visited_successors)
+                    .copied()
+                else {
+                    marks[*index] = Mark::Done;
+                    path.pop();
+                    continue;
+                };
+                *visited_successors += 1;
+                if !self.members[successor] {
+                    continue;
+                }
+
+                match marks[successor] {
+                    Mark::Unvisited => {
+                        marks[successor] = Mark::OnPath;
+                        path.push((successor, 0));
+                    }
+                    Mark::OnPath => {
+                        let start = path
+                            .iter()
+                            .position(|(index, _)| *index == successor)
+                            .unwrap();
+                        return Some(
+                            path[start..]
+                                .iter()
+                                .map(|(index, _)| self.graph.nodes[*index].clone())
+                                .collect(),
+                        );
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Every clique that can't be extended by another node, by Bron–Kerbosch
+    /// with pivoting. Edges count regardless of direction here, and nodes
+    /// within a clique keep the graph's order.
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut neighbors = vec![BTreeSet::new(); self.members.len()];
+        for index in self.member_indices() {
+            neighbors[index] = self.graph.successors[index]
+                .iter()
+                .chain(&self.graph.predecessors[index])
+                .copied()
+                .filter(|neighbor| *neighbor != index && self.members[*neighbor])
+                .collect();
+        }
+
+        let mut cliques = vec![];
+        bron_kerbosch(
+            &neighbors,
+            &mut vec![],
+            self.member_indices().collect(),
+            BTreeSet::new(),
+            &mut cliques,
+        );
+
+        cliques
+            .into_iter()
+            .map(|clique| {
+                clique
+                    .into_iter()
+                    .sorted()
+                    .map(|index| self.graph.nodes[index].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// A clique with as many nodes as possible, the first one found if there
+    /// are several. Empty for an empty graph.
+    pub fn maximum_clique(&self) -> Vec<N> {
+        self.maximal_cliques()
+            .into_iter()
+            .rev()
+            .max_by_key(|clique| clique.len())
+            .unwrap_or_default()
+    }
+
+    /// Groups of nodes that can all reach each other, by Tarjan's algorithm.
+    /// Components come out in reverse topological order, no edge leads from
+    /// a component to one listed after it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let len = self.members.len();
+        let mut order = vec![UNVISITED; len];
+        let mut lowlink = vec![UNVISITED; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut next_order = 0;
+
+        for root in self.member_indices() {
+            if order[root] != UNVISITED {
+                continue;
+            }
+
+            // Stands in for the recursion, each frame is a node and how many
+            // of its successors were looked at.
+            let mut frames = vec![(root, 0)];
+            while let Some((index, visited_successors)) = frames.last_mut() {
+                let index = *index;
+                if *visited_successors == 0 && order[index] == UNVISITED {
+                    order[index] = next_order;
+                    lowlink[index] = next_order;
+                    next_order += 1;
+                    stack.push(index);
+                    on_stack[index] = true;
+                }
+
+                let successor = self.graph.successors[index]
+                    .get(*visited_successors)
+                    .copied();
+                if let Some(successor) = successor {
+                    *visited_successors += 1;
+                    if !self.members[successor] {
+                        continue;
+                    }
+                    if order[successor] == UNVISITED {
+                        frames.push((successor, 0));
+                    } else if on_stack[successor] {
+                        lowlink[index] = lowlink[index].min(order[successor]);
+                    }
+                    continue;
+                }
+
+                frames.pop();
+                if let Some((parent, _)) = frames.last() {
+                    lowlink[*parent] = lowlink[*parent].min(lowlink[index]);
+                }
+                if lowlink[index] == order[index] {
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(self.graph.nodes[member].clone());
+                        if member == index {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// A path through every node exactly once. In a DAG it is unique if it
+    /// exists, this returns `None` for graphs with cycles.
+    pub fn hamiltonian_path(&self) -> Option<Vec<N>> {
+        self.topological_sort().ok().and_then(|t| {
+            t.iter()
+                .zip(t.iter().skip(1))
+                .all(|(src, dest)| self.graph.has_edge(src, dest))
+                .then_some(t)
+        })
+    }
+}
+
+/// A [`Graph`] whose edges have a cost, for days that boil a grid down to
+/// the few places that matter and the distances between them.
+#[derive(Debug, Clone)]
+pub struct WeightedGraph<N, W> {
+    graph: Graph<N>,
+    weights: HashMap<(usize, usize), W>,
+}
+
+impl<N, W> Default for WeightedGraph<N, W> {
+    fn default() -> Self {
+        Self {
+            graph: Graph::default(),
+            weights: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Copy> FromIterator<(N, N, W)> for WeightedGraph<N, W> {
+    fn from_iter<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        edges
+            .into_iter()
+            .fold(Self::default(), |mut graph, (src, dest, weight)| {
+                graph.add_edge(src, dest, weight);
+                graph
+            })
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Copy> WeightedGraph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.graph.add_node(node);
+    }
+
+    /// Adds an edge, or changes its weight if there already is one.
+    pub fn add_edge(&mut self, src: N, dest: N, weight: W) {
+        let src = self.graph.add_node_index(src);
+        let dest = self.graph.add_node_index(dest);
+        if !self.graph.successors[src].contains(&dest) {
+            self.graph.successors[src].push(dest);
+            self.graph.predecessors[dest].push(src);
+        }
+        self.weights.insert((src, dest), weight);
+    }
+
+    /// Adds edges both ways with the same weight.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: W) {
+        self.add_edge(a.clone(), b.clone(), weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// The graph without the weights.
+    pub fn graph(&self) -> &Graph<N> {
+        &self.graph
+    }
+
+    pub fn weight(&self, src: &N, dest: &N) -> Option<W> {
+        let src = self.graph.indices.get(src)?;
+        let dest = self.graph.indices.get(dest)?;
+        self.weights.get(&(*src, *dest)).copied()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, W)> {
+        self.graph.edges().map(|(src, dest)| {
+            let weight = self.weights[&(self.graph.indices[src], self.graph.indices[dest])];
+            (src, dest, weight)
+        })
+    }
+
+    /// Nodes `node` has an edge to, with the weight of that edge.
+    pub fn successors<'a>(&'a self, node: &N) -> impl 'a + Iterator<Item = (&'a N, W)> {
+        let src = self.graph.indices.get(node).copied();
+        self.graph.successors(node).map(move |dest| {
+            (
+                dest,
+                self.weights[&(src.unwrap(), self.graph.indices[dest])],
+            )
+        })
+    }
+}
+
+impl<N, W> WeightedGraph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    /// The cheapest way from `src` to `dest` as its total weight and the nodes
+    /// along it, both ends included. Weights must not be negative.
+    pub fn shortest_path(&self, src: &N, dest: &N) -> Option<(W, Vec<N>)> {
+        let src = *self.graph.indices.get(src)?;
+        let dest = *self.graph.indices.get(dest)?;
+        dijkstra(
+            [src],
+            |&node| {
+                self.graph.successors[node]
+                    .iter()
+                    .map(move |next| (*next, self.weights[&(node, *next)]))
+            },
+            |node| *node == dest,
+        )
+        .map(|(weight, path)| {
+            (
+                weight,
+                path.into_iter()
+                    .map(|index| self.graph.nodes[index].clone())
+                    .collect(),
+            )
+        })
+    }
+
+    /// The shortest distance from `src` to every node it can reach, itself
+    /// included, in the graph's order. Weights must not be negative.
+    pub fn distances_from(&self, src: &N) -> Vec<(N, W)> {
+        let Some(src) = self.graph.indices.get(src) else {
+            return vec![];
+        };
+
+        let mut distances: Vec<Option<W>> = vec![None; self.graph.len()];
+        let mut frontier = BinaryHeap::from([Reverse((W::default(), *src))]);
+        distances[*src] = Some(W::default());
+        while let Some(Reverse((distance, node))) = frontier.pop() {
+            if distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            for next in &self.graph.successors[node] {
+                let next_distance = distance + self.weights[&(node, *next)];
+                if distances[*next].is_none_or(|best| next_distance < best) {
+                    distances[*next] = Some(next_distance);
+                    frontier.push(Reverse((next_distance, *next)));
+                }
+            }
+        }
+
+        self.graph
+            .nodes
+            .iter()
+            .zip(distances)
+            .filter_map(|(node, distance)| Some((node.clone(), distance?)))
+            .collect()
+    }
+
+    /// Shortest paths between every pair of nodes by Floyd–Warshall, which
+    /// takes cubic time in the number of nodes but pays off when most pairs
+    /// get asked about.
+    pub fn all_pairs_shortest_paths(&self) -> AllPairsShortestPaths<'_, N, W> {
+        let len = self.graph.len();
+        let mut distances = vec![vec![None; len]; len];
+        let mut next_hops = vec![vec![None; len]; len];
+        for index in 0..len {
+            distances[index][index] = Some(W::default());
+            next_hops[index][index] = Some(index);
+        }
+        for (&(src, dest), weight) in &self.weights {
+            if src != dest {
+                distances[src][dest] = Some(*weight);
+                next_hops[src][dest] = Some(dest);
+            }
+        }
+
+        for via in 0..len {
+            for src in 0..len {
+                let Some(to_via) = distances[src][via] else {
+                    continue;
+                };
+                for dest in 0..len {
+                    let Some(from_via) = distances[via][dest] else {
+                        continue;
+                    };
+                    if distances[src][dest].is_none_or(|best| to_via + from_via < best) {
+                        distances[src][dest] = Some(to_via + from_via);
+                        next_hops[src][dest] = next_hops[src][via];
+                    }
+                }
+            }
+        }
+
+        AllPairsShortestPaths {
+            graph: &self.graph,
+            distances,
+            next_hops,
+        }
+    }
+}
+
+/// The result of [`WeightedGraph::all_pairs_shortest_paths`].
+#[derive(Debug, Clone)]
+pub struct AllPairsShortestPaths<'a, N, W> {
+    graph: &'a Graph<N>,
+    distances: Vec<Vec<Option<W>>>,
+    /// The node after the first on the shortest path between two nodes.
+    next_hops: Vec<Vec<Option<usize>>>,
+}
+
+impl<N: Clone + Eq + Hash, W: Copy> AllPairsShortestPaths<'_, N, W> {
+    /// Nothing if `dest` can't be reached from `src`.
+    pub fn distance(&self, src: &N, dest: &N) -> Option<W> {
+        self.distances[*self.graph.indices.get(src)?][*self.graph.indices.get(dest)?]
+    }
+
+    /// The nodes along a shortest path from `src` to `dest`, both ends
+    /// included.
+    pub fn path(&self, src: &N, dest: &N) -> Option<Vec<N>> {
+        let mut node = *self.graph.indices.get(src)?;
+        let dest = *self.graph.indices.get(dest)?;
+        let mut path = vec![self.graph.nodes[node].clone()];
+        while node != dest {
+            node = self.next_hops[node][dest]?;
+            path.push(self.graph.nodes[node].clone());
+        }
+        Some(path)
+    }
+}
+
+/// Reports every maximal clique that extends `clique` by nodes of
+/// `candidates`, none of which may be in `excluded`.
+fn bron_kerbosch(
+    neighbors: &[BTreeSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: BTreeSet<usize>,
+    mut excluded: BTreeSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+
+    // Any maximal clique contains the pivot or one of its non-neighbors, so
+    // branching on those alone is enough. Picking the pivot with the most
+    // candidate neighbors leaves the fewest branches.
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|node| neighbors[**node].intersection(&candidates).count())
+        .copied()
+        .unwrap();
+    let branches = candidates
+        .difference(&neighbors[pivot])
+        .copied()
+        .collect::<Vec<_>>();
+
+    for node in branches {
+        clique.push(node);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(&neighbors[node]).copied().collect(),
+            excluded.intersection(&neighbors[node]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+/// The graph has a cycle, so its nodes can't be ordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// One of the nodes on the cycle.
+    pub node: N,
+}
+
+impl<N: Debug> Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has a cycle through {:?}", self.node)
+    }
+}
+
+impl<N: Debug> std::error::Error for CycleError<N> {}
+
+#[test]
+fn adjacency() {
+    let graph: Graph<&str> = [("a", "b"), ("a", "c"), ("c", "b"), ("a", "b")]
+        .into_iter()
+        .collect();
+
+    assert_eq!(3, graph.len());
+    assert_eq!(
+        vec!["a", "b", "c"],
+        graph.nodes().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(3, graph.edges().count());
+    assert!(graph.has_edge(&"c", &"b"));
+    assert!(!graph.has_edge(&"b", &"c"));
+    assert_eq!(
+        vec!["b", "c"],
+        graph.successors(&"a").copied().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["a", "c"],
+        graph.predecessors(&"b").copied().collect::<Vec<_>>()
+    );
+    assert_eq!(0, graph.successors(&"z").count());
+
+    let subgraph = graph.subgraph(&["a", "b", "z"]);
+    assert_eq!(2, subgraph.len());
+    assert!(!subgraph.contains(&"c"));
+    assert_eq!(
+        vec!["b"],
+        subgraph.successors(&"a").copied().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn dot() {
+    let graph = Graph::with_edges(&[("a", "b"), ("b", "c"), ("c", "a"), ("a", "d")]);
+    assert_eq!(
+        r#"digraph {
+    "a";
+    "b";
+    "c";
+    "a" -> "b" [color=red];
+    "b" -> "c";
+    "c" -> "a";
+}
+"#,
+        graph
+            .subgraph(&["a", "b", "c"])
+            .to_dot(|src, dest| { (*src == "a" && *dest == "b").then(|| "color=red".to_owned()) })
+    );
+    assert_eq!(4, graph.to_dot(|_, _| None).matches(" -> ").count());
+}
+
+#[test]
+fn topological_sort_and_hamiltonian_path() {
+    let graph = Graph::with_edges(&[(0, 1), (0, 2), (1, 2), (2, 3), (3, 0)]);
+
+    let err = graph.topological_sort().unwrap_err();
+    assert!(graph.has_edge(&err.node, graph.successors(&err.node).next().unwrap()));
+    assert_eq!(
+        "the graph has a cycle through 0",
+        CycleError { node: 0 }.to_string()
+    );
+
+    assert_eq!(
+        Ok(vec![0, 1, 2]),
+        graph.subgraph(&[0, 1, 2]).topological_sort()
+    );
+    assert_eq!(
+        Some(vec![0, 1, 2]),
+        graph.subgraph(&[0, 1, 2]).hamiltonian_path()
+    );
+
+    let graph = Graph::with_edges(&[(0, 1), (2, 1)]);
+    assert_eq!(Ok(vec![2, 0, 1]), graph.topological_sort());
+    assert_eq!(None, graph.subgraph(graph.nodes()).hamiltonian_path());
+    assert_eq!(Ok(vec![]), Graph::<u8>::new().topological_sort());
+}
+
+#[test]
+fn strongly_connected_components() {
+    // a <-> b -> c -> d -> e -> c, f on its own.
+    let mut graph = Graph::with_edges(&[
+        ('a', 'b'),
+        ('b', 'a'),
+        ('b', 'c'),
+        ('c', 'd'),
+        ('d', 'e'),
+        ('e', 'c'),
+    ]);
+    graph.add_node('f');
+
+    assert_eq!(
+        vec![vec!['c', 'd', 'e'], vec!['a', 'b'], vec!['f']],
+        graph.strongly_connected_components()
+    );
+    assert_eq!(
+        vec![vec!['a', 'b'], vec!['e'], vec!['d']],
+        graph
+            .subgraph(&['a', 'b', 'd', 'e'])
+            .strongly_connected_components()
+    );
+
+    // Acyclic graphs fall apart into single nodes in reverse topological
+    // order.
+    let graph = Graph::with_edges(&[(0, 1), (2, 1), (1, 3)]);
+    let components = graph.strongly_connected_components();
+    assert!(components.iter().all(|component| component.len() == 1));
+    let mut order = components.concat();
+    order.reverse();
+    assert_eq!(Ok(order), graph.topological_sort());
+}
+
+#[test]
+fn find_cycle() {
+    let graph = Graph::with_edges(&[(0, 1), (1, 2), (2, 3), (3, 1), (0, 4)]);
+    assert_eq!(Some(vec![1, 2, 3]), graph.find_cycle());
+    assert_eq!(None, graph.subgraph(&[0, 1, 2, 4]).find_cycle());
+
+    let graph = Graph::with_edges(&[(0, 1), (1, 1)]);
+    assert_eq!(Some(vec![1]), graph.find_cycle());
+
+    let graph = Graph::with_edges(&[(0, 1), (0, 2), (1, 2)]);
+    assert_eq!(None, graph.find_cycle());
+}
+
+#[test]
+fn cliques() {
+    // A triangle sharing a node with a square that has one diagonal.
+    let graph = Graph::with_edges(&[
+        ('a', 'b'),
+        ('b', 'c'),
+        ('c', 'a'),
+        ('c', 'd'),
+        ('d', 'e'),
+        ('e', 'f'),
+        ('f', 'c'),
+        ('c', 'e'),
+    ]);
+    assert_eq!(
+        vec![
+            vec!['a', 'b', 'c'],
+            vec!['c', 'd', 'e'],
+            vec!['c', 'e', 'f']
+        ]
+        .into_iter()
+        .sorted()
+        .collect::<Vec<_>>(),
+        graph
+            .maximal_cliques()
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(3, graph.maximum_clique().len());
+    assert_eq!(
+        vec![vec!['d', 'e']],
+        graph
+            .subgraph(&['a', 'd', 'e'])
+            .maximal_cliques()
+            .into_iter()
+            .filter(|clique| clique.len() > 1)
+            .collect::<Vec<_>>()
+    );
+    assert!(Graph::<u8>::new().maximum_clique().is_empty());
+
+    // The network map from the example of 2024 day 23.
+    let edges = "kh-tc qp-kh de-cg ka-co yn-aq qp-ub cg-tb vc-aq tb-ka wh-tc yn-cg kh-ub ta-co \
+                 de-co tc-td tb-wq wh-td ta-ka td-qp aq-cg wq-ub ub-vc de-ta wq-aq wq-vc wh-yn \
+                 ka-de kh-ta co-tc wh-qp tb-vc td-yn";
+    let graph: Graph<&str> = edges
+        .split_whitespace()
+        .map(|edge| edge.split_once('-').unwrap())
+        .collect();
+    assert_eq!(
+        vec!["co", "de", "ka", "ta"],
+        graph
+            .maximum_clique()
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>()
+    );
+    assert!(graph.maximal_cliques().iter().all(|clique| clique
+        .iter()
+        .tuple_combinations()
+        .all(|(a, b)| { graph.has_edge(a, b) || graph.has_edge(b, a) })));
+}
+
+#[test]
+fn shortest_paths() {
+    let graph: WeightedGraph<char, u32> = [
+        ('a', 'b', 7),
+        ('a', 'c', 2),
+        ('c', 'b', 3),
+        ('b', 'd', 1),
+        ('c', 'd', 8),
+        ('d', 'a', 1),
+        ('e', 'a', 1),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(Some(3), graph.weight(&'c', &'b'));
+    assert_eq!(None, graph.weight(&'b', &'c'));
+    assert_eq!(vec![(&'d', 1)], graph.successors(&'b').collect::<Vec<_>>());
+
+    assert_eq!(
+        Some((6, vec!['a', 'c', 'b', 'd'])),
+        graph.shortest_path(&'a', &'d')
+    );
+    assert_eq!(Some((0, vec!['b'])), graph.shortest_path(&'b', &'b'));
+    assert_eq!(None, graph.shortest_path(&'a', &'e'));
+    assert_eq!(
+        vec![('a', 0), ('b', 5), ('c', 2), ('d', 6)],
+        graph.distances_from(&'a')
+    );
+
+    let all_pairs = graph.all_pairs_shortest_paths();
+    for src in graph.graph().nodes() {
+        let distances = graph.distances_from(src);
+        for dest in graph.graph().nodes() {
+            let distance = distances
+                .iter()
+                .find(|(node, _)| node == dest)
+                .map(|(_, distance)| *distance);
+            assert_eq!(distance, all_pairs.distance(src, dest));
+            assert_eq!(
+                graph.shortest_path(src, dest).map(|(_, path)| path),
+                all_pairs.path(src, dest)
+            );
+        }
+    }
+    assert_eq!(
+        Some(vec!['e', 'a', 'c', 'b', 'd']),
+        all_pairs.path(&'e', &'d')
+    );
+}
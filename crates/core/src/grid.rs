@@ -0,0 +1,953 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut, Range, RangeBounds},
+};
+
+use itertools::Itertools;
+
+use crate::graph::Graph;
+
+/// Cells are stored row after row in one `Vec`, `size` says where a row ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    size: GridSize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row_index: usize,
+    pub col_index: usize,
+}
+
+impl Position {
+    pub fn new(row_index: usize, col_index: usize) -> Position {
+        Position {
+            row_index,
+            col_index,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Offset {
+    pub row_offset: isize,
+    pub col_offset: isize,
+}
+
+impl Offset {
+    pub const fn new(row_offset: isize, col_offset: isize) -> Offset {
+        Offset {
+            row_offset,
+            col_offset,
+        }
+    }
+
+    pub fn dot(&self, r: Offset) -> isize {
+        self.row_offset * r.row_offset + self.col_offset * r.col_offset
+    }
+
+    pub const DOWN: Offset = Offset {
+        row_offset: 1,
+        col_offset: 0,
+    };
+
+    pub const UP: Offset = Offset {
+        row_offset: -1,
+        col_offset: 0,
+    };
+
+    pub const LEFT: Offset = Offset {
+        row_offset: 0,
+        col_offset: -1,
+    };
+
+    pub const RIGHT: Offset = Offset {
+        row_offset: 0,
+        col_offset: 1,
+    };
+
+    pub const ORTHOGONAL: [Offset; 4] = [Offset::UP, Offset::DOWN, Offset::LEFT, Offset::RIGHT];
+
+    /// The eight offsets around a cell in reading order.
+    pub const SURROUNDING: [Offset; 8] = [
+        Offset::new(-1, -1),
+        Offset::UP,
+        Offset::new(-1, 1),
+        Offset::LEFT,
+        Offset::RIGHT,
+        Offset::new(1, -1),
+        Offset::DOWN,
+        Offset::new(1, 1),
+    ];
+}
+
+/// One of the four ways to step between orthogonal neighbours, with up
+/// being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// How many quarter turns it takes to face `other`, either way round.
+    pub fn turns_to(self, other: Direction) -> u8 {
+        match (other as u8 + 4 - self as u8) % 4 {
+            3 => 1,
+            turns => turns,
+        }
+    }
+
+    pub fn to_offset(self) -> Offset {
+        match self {
+            Direction::Up => Offset::UP,
+            Direction::Right => Offset::RIGHT,
+            Direction::Down => Offset::DOWN,
+            Direction::Left => Offset::LEFT,
+        }
+    }
+
+    /// How the puzzles draw someone facing this way, `^>v<`.
+    pub fn to_arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Constraints<R>
+where
+    R: RangeBounds<usize>,
+{
+    pub row_range: R,
+    pub col_range: R,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridSize(pub usize, pub usize);
+
+impl Into<Constraints<Range<usize>>> for GridSize {
+    fn into(self) -> Constraints<Range<usize>> {
+        Constraints {
+            row_range: 0..self.0,
+            col_range: 0..self.1,
+        }
+    }
+}
+
+impl Position {
+    #[inline]
+    pub fn checked_add_offset<R>(&self, offset: Offset, constraints: Constraints<R>) -> Option<Self>
+    where
+        R: RangeBounds<usize>,
+    {
+        let row_index = self
+            .row_index
+            .checked_add_signed(offset.row_offset)
+            .filter(|row_index| constraints.row_range.contains(row_index))?;
+        let col_index = self
+            .col_index
+            .checked_add_signed(offset.col_offset)
+            .filter(|col_index| constraints.col_range.contains(col_index))?;
+        Some(Position::new(row_index, col_index))
+    }
+}
+
+impl Position {
+    /// Steps by `offset` on a torus: leaving the grid on one side re-enters
+    /// it from the opposite side.
+    #[inline]
+    pub fn wrapping_add_offset(&self, offset: Offset, grid_size: GridSize) -> Self {
+        let GridSize(rows, cols) = grid_size;
+        Position::new(
+            wrapping_add(self.row_index, offset.row_offset, rows),
+            wrapping_add(self.col_index, offset.col_offset, cols),
+        )
+    }
+}
+
+#[inline]
+fn wrapping_add(index: usize, offset: isize, len: usize) -> usize {
+    assert!(len > 0);
+    let sum = i128::try_from(index).unwrap() + i128::try_from(offset).unwrap();
+    usize::try_from(sum.rem_euclid(i128::try_from(len).unwrap())).unwrap()
+}
+
+impl Offset {
+    #[inline]
+    pub fn unchecked_add(&self, r: Offset) -> Offset {
+        Offset::new(
+            self.row_offset + r.row_offset,
+            self.col_offset + r.col_offset,
+        )
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = String;
+
+    fn try_from(value: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let rows = value.len();
+        let cols = value.first().map(|row| row.len()).unwrap_or(0);
+        value
+            .iter()
+            .all(|row| row.len() == cols)
+            .then(|| Grid {
+                cells: value.into_iter().flatten().collect_vec(),
+                size: GridSize(rows, cols),
+            })
+            .ok_or("ambiguous column length".to_string())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if the rows are not all the same length.
+    pub fn new(inner: Vec<Vec<T>>) -> Self {
+        Self::try_from(inner).unwrap()
+    }
+
+    /// Parses rows of `cell`s separated by line endings, failing if the rows
+    /// are not all the same length. A row ends in a line ending or the end of
+    /// the input, anything else `cell` rejects fails the grid right there
+    /// rather than cutting it short.
+    pub fn parse<'a, P>(cell: P) -> impl nom::Parser<&'a str, Self, nom::error::Error<&'a str>>
+    where
+        P: nom::Parser<&'a str, T, nom::error::Error<&'a str>>,
+    {
+        nom::combinator::map_res(
+            nom::multi::separated_list1(nom::character::complete::line_ending, row(cell)),
+            Self::try_from,
+        )
+    }
+
+    /// [`Grid::parse`] for the bytes of an input, see
+    /// [`crate::parse_util::bytes`].
+    pub fn parse_bytes<'a, P>(
+        cell: P,
+    ) -> impl nom::Parser<&'a [u8], Self, nom::error::Error<&'a [u8]>>
+    where
+        P: nom::Parser<&'a [u8], T, nom::error::Error<&'a [u8]>>,
+    {
+        nom::combinator::map_res(
+            nom::multi::separated_list1(nom::character::complete::line_ending, row(cell)),
+            Self::try_from,
+        )
+    }
+
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let GridSize(rows, cols) = self.size;
+        let mut cells = self.cells.into_iter();
+        (0..rows)
+            .map(|_| cells.by_ref().take(cols).collect_vec())
+            .collect_vec()
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.into_iter().map(f).collect_vec(),
+            size: self.size,
+        }
+    }
+
+    pub fn fill_with(elm: T, grid_size: GridSize) -> Self
+    where
+        T: Clone,
+    {
+        let GridSize(rows, cols) = grid_size;
+        Grid {
+            cells: vec![elm; rows * cols],
+            size: grid_size,
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> GridSize {
+        self.size
+    }
+
+    #[inline]
+    fn checked_index(&self, position: Position) -> Option<usize> {
+        let GridSize(rows, cols) = self.size;
+        (position.row_index < rows && position.col_index < cols)
+            .then(|| position.row_index * cols + position.col_index)
+    }
+
+    #[inline]
+    fn index(&self, position: Position) -> usize {
+        self.checked_index(position).unwrap_or_else(|| {
+            let GridSize(rows, cols) = self.size;
+            panic!("{position:?} is out of a {rows}x{cols} grid")
+        })
+    }
+
+    #[inline]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.checked_index(position).map(|index| &self.cells[index])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.checked_index(position)
+            .map(|index| &mut self.cells[index])
+    }
+
+    #[inline]
+    pub fn must_get_cell<'a>(&'a self, position: Position) -> &'a T {
+        &self.cells[self.index(position)]
+    }
+
+    #[inline]
+    pub fn must_get_mut_cell<'a>(&'a mut self, position: Position) -> &'a mut T {
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+
+    pub fn positions<'a>(&'a self) -> impl 'a + Iterator<Item = Position> {
+        positions_within(self.size)
+    }
+
+    fn neighbors<const N: usize>(
+        &self,
+        position: Position,
+        offsets: [Offset; N],
+    ) -> impl Iterator<Item = Position> {
+        let grid_size = self.size;
+        offsets
+            .into_iter()
+            .filter_map(move |offset| position.checked_add_offset(offset, grid_size.into()))
+    }
+
+    /// In-bounds positions above, below, left and right of `position`.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(position, Offset::ORTHOGONAL)
+    }
+
+    /// In-bounds positions around `position`, diagonals included.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbors(position, Offset::SURROUNDING)
+    }
+
+    pub fn neighbor_cells4<'a>(
+        &'a self,
+        position: Position,
+    ) -> impl 'a + Iterator<Item = (Position, &'a T)> {
+        self.neighbors4(position)
+            .map(|position| (position, &self[position]))
+    }
+
+    pub fn neighbor_cells8<'a>(
+        &'a self,
+        position: Position,
+    ) -> impl 'a + Iterator<Item = (Position, &'a T)> {
+        self.neighbors8(position)
+            .map(|position| (position, &self[position]))
+    }
+
+    /// The passable cells as nodes, with edges both ways between passable
+    /// cells above, below, left or right of each other. Nodes are in row
+    /// order.
+    pub fn to_graph(&self, passable: impl Fn(&T) -> bool) -> Graph<Position> {
+        let mut graph = Graph::new();
+        for (position, _) in self.iter().filter(|(_, cell)| passable(cell)) {
+            graph.add_node(position);
+        }
+        for position in graph.nodes().copied().collect::<Vec<_>>() {
+            for (neighbor, _) in self
+                .neighbor_cells4(position)
+                .filter(|(_, cell)| passable(cell))
+            {
+                graph.add_edge(position, neighbor);
+            }
+        }
+        graph
+    }
+
+    /// Positions on the outermost ring, clockwise from the top left corner,
+    /// each exactly once even when the grid is a single row or column.
+    pub fn border_positions(&self) -> impl Iterator<Item = Position> {
+        let GridSize(rows, cols) = self.size;
+        let (last_row, last_col) = (rows.saturating_sub(1), cols.saturating_sub(1));
+
+        let top = (0..cols).map(|col_index| Position::new(0, col_index));
+        let right = (1..rows).map(move |row_index| Position::new(row_index, last_col));
+        let bottom = (0..last_col)
+            .rev()
+            .filter(move |_| rows > 1)
+            .map(move |col_index| Position::new(last_row, col_index));
+        let left = (1..last_row)
+            .rev()
+            .filter(move |_| cols > 1)
+            .map(|row_index| Position::new(row_index, 0));
+
+        top.chain(right)
+            .chain(bottom)
+            .chain(left)
+            .filter(move |_| rows > 0 && cols > 0)
+    }
+
+    pub fn perimeter_cells<'a>(&'a self) -> impl 'a + Iterator<Item = (Position, &'a T)> {
+        self.border_positions()
+            .map(|position| (position, self.must_get_cell(position)))
+    }
+
+    pub fn rows<'a>(&'a self) -> impl 'a + Iterator<Item = &'a [T]> {
+        let GridSize(rows, cols) = self.size;
+        (0..rows).map(move |row_index| &self.cells[row_index * cols..(row_index + 1) * cols])
+    }
+
+    fn rows_mut<'a>(&'a mut self) -> impl 'a + Iterator<Item = &'a mut [T]> {
+        let GridSize(_, cols) = self.size;
+        // With no columns there are no cells either, so the chunk size only
+        // needs to be non-zero.
+        self.cells.chunks_mut(cols.max(1))
+    }
+
+    pub fn cols<'a>(&'a self) -> impl 'a + Iterator<Item = impl 'a + Iterator<Item = &'a T>> {
+        let GridSize(rows, cols) = self.size;
+        (0..cols).map(move |col_index| {
+            (0..rows).map(move |row_index| &self.cells[row_index * cols + col_index])
+        })
+    }
+
+    pub fn iter<'a>(&'a self) -> impl 'a + Iterator<Item = (Position, &'a T)> {
+        let GridSize(_, cols) = self.size;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (Position::new(index / cols, index % cols), cell))
+    }
+
+    pub fn iter_mut<'a>(&'a mut self) -> impl 'a + Iterator<Item = (Position, &'a mut T)> {
+        let GridSize(_, cols) = self.size;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| (Position::new(index / cols, index % cols), cell))
+    }
+
+    pub fn transpose(self) -> Self {
+        let GridSize(rows, cols) = self.size;
+        Grid {
+            cells: self
+                .cells
+                .into_iter()
+                .enumerate()
+                .sorted_by_key(|(index, _)| (index % cols, index / cols))
+                .map(|(_, cell)| cell)
+                .collect_vec(),
+            size: GridSize(cols, rows),
+        }
+    }
+
+    /// Rotates a quarter turn clockwise, the first column becomes the first
+    /// row read bottom to top.
+    pub fn rotate_cw(self) -> Self {
+        let mut grid = self.transpose();
+        grid.rows_mut().for_each(|row| row.reverse());
+        grid
+    }
+
+    /// Rotates a quarter turn counterclockwise, the last column becomes the
+    /// first row.
+    pub fn rotate_ccw(self) -> Self {
+        let mut grid = self.transpose();
+        grid.cells.reverse();
+        grid.rows_mut().for_each(|row| row.reverse());
+        grid
+    }
+
+    /// Draws the grid one line per row, `f` decides how each cell looks.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .join("\n")
+    }
+
+    /// Borrows the cells within `row_range` and `col_range`, panics if they
+    /// reach outside the grid.
+    pub fn view(&self, row_range: Range<usize>, col_range: Range<usize>) -> GridView<'_, T> {
+        let GridSize(rows, cols) = self.size;
+        assert!(
+            row_range.start <= row_range.end && row_range.end <= rows,
+            "rows {row_range:?} are out of a {rows}x{cols} grid"
+        );
+        assert!(
+            col_range.start <= col_range.end && col_range.end <= cols,
+            "columns {col_range:?} are out of a {rows}x{cols} grid"
+        );
+
+        GridView {
+            grid: self,
+            origin: Position::new(row_range.start, col_range.start),
+            size: GridSize(row_range.len(), col_range.len()),
+        }
+    }
+
+    /// Every `rows`x`cols` view that fits in the grid, in reading order of
+    /// their top left corners.
+    pub fn windows<'a>(
+        &'a self,
+        rows: usize,
+        cols: usize,
+    ) -> impl 'a + Iterator<Item = GridView<'a, T>> {
+        let GridSize(grid_rows, grid_cols) = self.size;
+        let corners = GridSize(
+            (grid_rows + 1).saturating_sub(rows),
+            (grid_cols + 1).saturating_sub(cols),
+        );
+        positions_within(corners).map(move |corner| {
+            self.view(
+                corner.row_index..corner.row_index + rows,
+                corner.col_index..corner.col_index + cols,
+            )
+        })
+    }
+}
+
+fn positions_within(GridSize(rows, cols): GridSize) -> impl Iterator<Item = Position> {
+    (0..rows).flat_map(move |row_index| {
+        (0..cols).map(move |col_index| Position::new(row_index, col_index))
+    })
+}
+
+/// A borrowed rectangle of a [`Grid`], made by [`Grid::view`] or
+/// [`Grid::windows`]. Positions are relative to its top left corner.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Position,
+    size: GridSize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    #[inline]
+    pub fn size(&self) -> GridSize {
+        self.size
+    }
+
+    /// Where the view's top left corner is in the underlying grid.
+    #[inline]
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    #[inline]
+    pub fn get(&self, position: Position) -> Option<&'a T> {
+        let GridSize(rows, cols) = self.size;
+        (position.row_index < rows && position.col_index < cols).then(|| {
+            self.grid.must_get_cell(Position::new(
+                self.origin.row_index + position.row_index,
+                self.origin.col_index + position.col_index,
+            ))
+        })
+    }
+
+    #[inline]
+    pub fn must_get_cell(&self, position: Position) -> &'a T {
+        self.get(position).unwrap_or_else(|| {
+            let GridSize(rows, cols) = self.size;
+            panic!("{position:?} is out of a {rows}x{cols} view")
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        positions_within(self.size)
+    }
+
+    pub fn rows(&self) -> impl 'a + Iterator<Item = &'a [T]> {
+        let GridSize(_, cols) = self.size;
+        let origin = self.origin;
+        let col_range = origin.col_index..origin.col_index + cols;
+        self.grid
+            .rows()
+            .skip(origin.row_index)
+            .take(self.size.0)
+            .map(move |row| &row[col_range.clone()])
+    }
+
+    pub fn iter(&self) -> impl 'a + Iterator<Item = (Position, &'a T)> {
+        let view = *self;
+        positions_within(self.size).map(move |position| (position, view.must_get_cell(position)))
+    }
+
+    /// Copies the viewed cells into a grid of their own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.rows().flatten().cloned().collect_vec(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Index<Position> for GridView<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, position: Position) -> &T {
+        self.must_get_cell(position)
+    }
+}
+
+/// `cell`s up to the end of the line, see [`Grid::parse`].
+fn row<I, T, P>(cell: P) -> impl nom::Parser<I, Vec<T>, nom::error::Error<I>>
+where
+    I: Clone
+        + nom::InputLength
+        + nom::InputIter
+        + nom::InputTakeAtPosition
+        + nom::Slice<std::ops::Range<usize>>
+        + nom::Slice<std::ops::RangeFrom<usize>>
+        + nom::Slice<std::ops::RangeTo<usize>>
+        + nom::Compare<&'static str>,
+    <I as nom::InputTakeAtPosition>::Item: nom::AsChar + Clone,
+    P: nom::Parser<I, T, nom::error::Error<I>>,
+{
+    nom::sequence::terminated(
+        nom::multi::many1(cell),
+        nom::combinator::cut(nom::combinator::peek(nom::branch::alt((
+            nom::combinator::all_consuming(nom::character::complete::multispace0),
+            nom::character::complete::line_ending,
+        )))),
+    )
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, position: Position) -> &T {
+        self.must_get_cell(position)
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.must_get_mut_cell(position)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|cell| *cell))
+    }
+}
+
+#[test]
+fn iterators() {
+    let mut grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    assert_eq!(
+        vec![&[1, 2, 3][..], &[4, 5, 6][..]],
+        grid.rows().collect_vec()
+    );
+    assert_eq!(
+        vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+        grid.cols()
+            .map(|col| col.copied().collect_vec())
+            .collect_vec()
+    );
+    assert_eq!(
+        grid.positions().collect_vec(),
+        grid.iter().map(|(position, _)| position).collect_vec()
+    );
+
+    grid.iter_mut()
+        .for_each(|(position, cell)| *cell += position.row_index * 10);
+    assert_eq!(Some((Position::new(1, 2), &16)), grid.iter().last());
+}
+
+#[test]
+fn render() {
+    let grid = Grid::new(vec![vec![true, false], vec![false, true]]);
+    assert_eq!("#.\n.#", grid.render(|cell| if *cell { '#' } else { '.' }));
+
+    let grid = Grid::new(vec!["ab".chars().collect_vec(), "cd".chars().collect_vec()]);
+    assert_eq!("ab\ncd", grid.to_string());
+}
+
+#[test]
+fn parse() {
+    use nom::Parser;
+
+    let mut digits = Grid::parse(nom::character::complete::satisfy(|ch| ch.is_ascii_digit()));
+
+    assert_eq!(
+        Ok(("", Grid::new(vec![vec!['1', '2'], vec!['3', '4']]))),
+        digits.parse("12\r\n34")
+    );
+    assert!(digits.parse("12\n345").is_err());
+    assert_eq!(
+        Ok((" \n", Grid::new(vec![vec!['1']]))),
+        digits.parse("1 \n")
+    );
+
+    // A cell the grid can't have stops it where it is.
+    assert_eq!(
+        Err(nom::Err::Failure(nom::error::Error::new(
+            "é4",
+            nom::error::ErrorKind::CrLf
+        ))),
+        digits.parse("12\n3é4")
+    );
+}
+
+#[test]
+fn transpose_and_rotate() {
+    let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    assert_eq!(
+        Grid::new(vec![vec![1, 4], vec![2, 5], vec![3, 6]]),
+        grid.clone().transpose()
+    );
+    assert_eq!(
+        Grid::new(vec![vec![4, 1], vec![5, 2], vec![6, 3]]),
+        grid.clone().rotate_cw()
+    );
+    assert_eq!(
+        Grid::new(vec![vec![3, 6], vec![2, 5], vec![1, 4]]),
+        grid.clone().rotate_ccw()
+    );
+    assert_eq!(grid, grid.clone().rotate_cw().rotate_ccw());
+    assert_eq!(
+        grid.clone().rotate_cw().rotate_cw(),
+        grid.clone().rotate_ccw().rotate_ccw()
+    );
+}
+
+#[test]
+fn flat_storage() {
+    let mut grid = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+    assert_eq!(GridSize(2, 3), grid.size());
+    assert_eq!('f', *grid.must_get_cell(Position::new(1, 2)));
+
+    *grid.must_get_mut_cell(Position::new(0, 1)) = 'x';
+    assert_eq!(
+        vec![vec!['a', 'x', 'c'], vec!['d', 'e', 'f']],
+        grid.clone().into_rows()
+    );
+
+    assert_eq!(GridSize(3, 2), Grid::fill_with(0, GridSize(3, 2)).size());
+    assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+    grid.must_get_cell(Position::new(0, 2));
+}
+
+#[test]
+fn border() {
+    let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    assert_eq!(
+        vec![1, 2, 3, 6, 9, 8, 7, 4],
+        grid.perimeter_cells().map(|(_, cell)| *cell).collect_vec()
+    );
+
+    let row = Grid::new(vec![vec![1, 2, 3]]);
+    assert_eq!(
+        vec![1, 2, 3],
+        row.perimeter_cells().map(|(_, cell)| *cell).collect_vec()
+    );
+
+    let col = Grid::new(vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(
+        vec![1, 2, 3],
+        col.perimeter_cells().map(|(_, cell)| *cell).collect_vec()
+    );
+
+    assert_eq!(0, Grid::<u8>::new(vec![]).border_positions().count());
+}
+
+#[test]
+fn wrapping() {
+    let size = GridSize(7, 11);
+    assert_eq!(
+        Position::new(1, 3),
+        Position::new(4, 2).wrapping_add_offset(Offset::new(-3, 1), size)
+    );
+    assert_eq!(
+        Position::new(6, 0),
+        Position::new(0, 10).wrapping_add_offset(Offset::new(-1, 1), size)
+    );
+    assert_eq!(
+        Position::new(2, 5),
+        Position::new(2, 5).wrapping_add_offset(Offset::new(-70, 110), size)
+    );
+}
+
+#[test]
+fn get() {
+    let mut grid = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+
+    assert_eq!(Some(&4), grid.get(Position::new(1, 1)));
+    assert_eq!(None, grid.get(Position::new(0, 2)));
+    assert_eq!(None, grid.get_mut(Position::new(2, 0)));
+
+    *grid.get_mut(Position::new(0, 0)).unwrap() = 5;
+    grid[Position::new(0, 1)] += 1;
+    assert_eq!(vec![vec![5, 3], vec![3, 4]], grid.clone().into_rows());
+    assert_eq!(3, grid[Position::new(1, 0)]);
+}
+
+#[test]
+fn neighbors() {
+    let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+    assert_eq!(
+        vec![2, 8, 4, 6],
+        grid.neighbor_cells4(Position::new(1, 1))
+            .map(|(_, cell)| *cell)
+            .collect_vec()
+    );
+    assert_eq!(
+        vec![1, 2, 3, 4, 6, 7, 8, 9],
+        grid.neighbor_cells8(Position::new(1, 1))
+            .map(|(_, cell)| *cell)
+            .collect_vec()
+    );
+    assert_eq!(
+        vec![Position::new(1, 0), Position::new(0, 1)],
+        grid.neighbors4(Position::new(0, 0)).collect_vec()
+    );
+    assert_eq!(3, grid.neighbors8(Position::new(2, 2)).count());
+}
+
+#[test]
+fn views() {
+    let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+    let view = grid.view(1..3, 0..2);
+    assert_eq!(GridSize(2, 2), view.size());
+    assert_eq!(Position::new(1, 0), view.origin());
+    assert_eq!(8, view[Position::new(1, 1)]);
+    assert_eq!(None, view.get(Position::new(0, 2)));
+    assert_eq!(vec![&[4, 5][..], &[7, 8][..]], view.rows().collect_vec());
+    assert_eq!(
+        vec![4, 5, 7, 8],
+        view.iter().map(|(_, cell)| *cell).collect_vec()
+    );
+    assert_eq!(Grid::new(vec![vec![4, 5], vec![7, 8]]), view.to_grid());
+
+    assert_eq!(GridSize(0, 3), grid.view(3..3, 0..3).size());
+}
+
+#[test]
+#[should_panic]
+fn view_out_of_bounds() {
+    let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+    grid.view(0..2, 1..3);
+}
+
+#[test]
+fn windows() {
+    let grid = Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    assert_eq!(
+        vec![vec![1, 2, 4, 5], vec![2, 3, 5, 6]],
+        grid.windows(2, 2)
+            .map(|window| window.iter().map(|(_, cell)| *cell).collect_vec())
+            .collect_vec()
+    );
+    assert_eq!(
+        vec![
+            Position::new(0, 0),
+            Position::new(0, 2),
+            Position::new(1, 1)
+        ],
+        grid.windows(1, 1)
+            .map(|window| window.origin())
+            .step_by(2)
+            .collect_vec()
+    );
+    assert_eq!(0, grid.windows(3, 1).count());
+    assert_eq!(1, grid.windows(2, 3).count());
+}
+
+#[test]
+fn to_graph() {
+    let grid = Grid::new(vec![
+        "..#".chars().collect(),
+        ".##".chars().collect(),
+        "#..".chars().collect(),
+    ]);
+    let graph = grid.to_graph(|cell| *cell == '.');
+
+    assert_eq!(5, graph.len());
+    assert_eq!(
+        vec![
+            Position::new(0, 0),
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(2, 1),
+            Position::new(2, 2),
+        ],
+        graph.nodes().copied().collect::<Vec<_>>()
+    );
+    assert!(graph.has_edge(&Position::new(0, 1), &Position::new(0, 0)));
+    assert!(graph.has_edge(&Position::new(0, 0), &Position::new(0, 1)));
+    assert!(!graph.has_edge(&Position::new(1, 0), &Position::new(2, 1)));
+    assert_eq!(6, graph.edges().count());
+    assert_eq!(2, graph.strongly_connected_components().len());
+}
+
+#[test]
+fn directions() {
+    for direction in Direction::ALL {
+        assert_eq!(direction, direction.turn_right().turn_left());
+        assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+        assert_eq!(direction.opposite(), direction.turn_left().turn_left());
+        assert_eq!(
+            Offset::new(0, 0),
+            direction
+                .to_offset()
+                .unchecked_add(direction.opposite().to_offset())
+        );
+        assert_eq!(0, direction.turns_to(direction));
+        assert_eq!(1, direction.turns_to(direction.turn_left()));
+        assert_eq!(1, direction.turns_to(direction.turn_right()));
+        assert_eq!(2, direction.turns_to(direction.opposite()));
+    }
+    assert_eq!(Direction::Right, Direction::Up.turn_right());
+    assert_eq!(Offset::LEFT, Direction::Left.to_offset());
+}
@@ -0,0 +1,163 @@
+use crate::error::ParseError;
+
+/// Parse state of a line-oriented puzzle input which can be brought up to date
+/// with a newer version of the input.
+///
+/// Lines terminated by a newline are considered final: as long as the newer
+/// input starts with them, their parsed values are reused and only the lines
+/// after them are parsed. The trailing unterminated line is always re-parsed,
+/// since it might still be in the middle of being pasted. Any other change
+/// falls back to parsing the whole input again.
+#[derive(Debug, Clone)]
+pub struct Incremental<T> {
+    committed_input: String,
+    committed_lines: usize,
+    /// Offset of the first of the blank lines at the end of `committed_input`,
+    /// if it ends with any.
+    committed_blank: Option<usize>,
+    lines: Vec<T>,
+}
+
+impl<T> Default for Incremental<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Incremental<T> {
+    pub fn new() -> Self {
+        Self {
+            committed_input: String::new(),
+            committed_lines: 0,
+            committed_blank: None,
+            lines: vec![],
+        }
+    }
+
+    /// Brings the parsed lines up to date with `input`.
+    ///
+    /// Blank lines are only accepted at the end of the input, as with the
+    /// days' batch parsers. Errors point into `input` as a whole rather than
+    /// into the line `parse_line` failed on.
+    pub fn update<F>(&mut self, input: &str, mut parse_line: F) -> Result<&[T], ParseError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError>,
+    {
+        if !input.starts_with(&self.committed_input) {
+            self.committed_input.clear();
+            self.committed_lines = 0;
+            self.committed_blank = None;
+        }
+        self.lines.truncate(self.committed_lines);
+
+        let mut start = self.committed_input.len();
+
+        while let Some(len) = input[start..].find('\n') {
+            let line = &input[start..start + len];
+            if line.trim().is_empty() {
+                self.committed_blank.get_or_insert(start);
+            } else {
+                self.lines
+                    .push(self.parse(input, start, line, &mut parse_line)?);
+                self.committed_lines += 1;
+            }
+            self.committed_input.push_str(line);
+            self.committed_input.push('\n');
+            start += len + 1;
+        }
+
+        let remaining = &input[start..];
+        if !remaining.trim().is_empty() {
+            let line = self.parse(input, start, remaining, &mut parse_line)?;
+            self.lines.push(line);
+        }
+
+        Ok(&self.lines)
+    }
+
+    /// Parses the non-blank `line` starting at `start` in `input`.
+    fn parse<F>(
+        &self,
+        input: &str,
+        start: usize,
+        line: &str,
+        parse_line: &mut F,
+    ) -> Result<T, ParseError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError>,
+    {
+        if let Some(blank) = self.committed_blank {
+            return Err(ParseError::new(
+                input,
+                blank,
+                "unexpected blank line".to_owned(),
+            ));
+        }
+        parse_line(line).map_err(|err| ParseError::new(input, start + err.offset, err.message))
+    }
+}
+
+#[test]
+fn reuses_committed_lines() {
+    use crate::error::parse_complete;
+    use std::cell::Cell;
+
+    let parsed_lines = Cell::new(0);
+    let parse_line = |line: &str| {
+        parsed_lines.set(parsed_lines.get() + 1);
+        parse_complete(nom::character::complete::u64, line)
+    };
+
+    let mut state = Incremental::new();
+
+    assert_eq!(state.update("1\n2\n3", parse_line).unwrap(), &[1, 2, 3]);
+    assert_eq!(parsed_lines.replace(0), 3);
+
+    assert_eq!(
+        state.update("1\n2\n34\n5", parse_line).unwrap(),
+        &[1, 2, 34, 5]
+    );
+    assert_eq!(parsed_lines.replace(0), 2);
+
+    assert_eq!(
+        state.update("1\n2\n34\n5\n", parse_line).unwrap(),
+        &[1, 2, 34, 5]
+    );
+    assert_eq!(parsed_lines.replace(0), 1);
+
+    assert_eq!(state.update("7\n2", parse_line).unwrap(), &[7, 2]);
+    assert_eq!(parsed_lines.replace(0), 2);
+
+    assert!(state.update("7\nx", parse_line).is_err());
+    assert_eq!(state.update("7\n8", parse_line).unwrap(), &[7, 8]);
+}
+
+#[test]
+fn errors_point_into_whole_input() {
+    use crate::error::parse_complete;
+
+    let parse_line = |line: &str| parse_complete(nom::character::complete::u64, line);
+    let mut state = Incremental::new();
+
+    state.update("1\n2\n", parse_line).unwrap();
+    let err = state.update("1\n2\n3x\n4", parse_line).unwrap_err();
+    assert_eq!((err.offset, err.line, err.column), (5, 3, 2));
+
+    let err = state.update("1\n2\n3\n4x", parse_line).unwrap_err();
+    assert_eq!((err.offset, err.line, err.column), (7, 4, 2));
+}
+
+#[test]
+fn trailing_blank_lines() {
+    use crate::error::parse_complete;
+
+    let parse_line = |line: &str| parse_complete(nom::character::complete::u64, line);
+    let mut state = Incremental::new();
+
+    assert_eq!(state.update("1\n2\n\n", parse_line).unwrap(), &[1, 2]);
+    assert_eq!(state.update("1\n2\n\n \n", parse_line).unwrap(), &[1, 2]);
+
+    let err = state.update("1\n2\n\n \n3", parse_line).unwrap_err();
+    assert_eq!((err.offset, err.line, err.column), (4, 3, 1));
+    assert_eq!(state.update("1\n2\n3", parse_line).unwrap(), &[1, 2, 3]);
+}
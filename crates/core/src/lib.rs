@@ -0,0 +1,47 @@
+#![feature(trait_alias)]
+#![feature(test)]
+
+#[cfg(test)]
+extern crate test;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub mod answer;
+pub mod day;
+pub mod diagram;
+pub mod disjoint_set;
+pub mod error;
+pub mod examples;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod golden;
+pub mod graph;
+pub mod grid;
+pub mod incremental;
+pub mod memo;
+pub mod par;
+pub mod parse_util;
+pub mod pathfinding;
+pub mod phase;
+pub mod registry;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod search;
+#[cfg(test)]
+mod strategies;
+pub mod validate;
+pub mod viz;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+macro_rules! implemented_days {
+    ($(($number:literal, $module:ident)),* $(,)?) => {
+        /// Every day with a module in this crate, in order.
+        pub const IMPLEMENTED_DAYS: &[day::Day] = &[$(day::Day::new($number)),*];
+    };
+}
+
+with_days!(implemented_days);
@@ -0,0 +1,72 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Results of a recursive function by argument, so each is only worked out
+/// once. The function gets the memo passed back in to use for its own
+/// recursive calls.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key`, computing and caching it first if there
+    /// is none. `compute` must not ask for `key` itself, that would recurse
+    /// forever.
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// How many results are cached.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[test]
+fn fibonacci() {
+    fn fibonacci(memo: &mut Memo<u64, u64>, calls: &mut usize, n: u64) -> u64 {
+        memo.get_or_insert_with(n, |memo| {
+            *calls += 1;
+            if n < 2 {
+                n
+            } else {
+                fibonacci(memo, calls, n - 1) + fibonacci(memo, calls, n - 2)
+            }
+        })
+    }
+
+    let mut memo = Memo::new();
+    let mut calls = 0;
+    assert_eq!(12586269025, fibonacci(&mut memo, &mut calls, 50));
+    assert_eq!(51, calls);
+    assert_eq!(51, memo.len());
+    assert_eq!(Some(&55), memo.get(&10));
+
+    assert_eq!(832040, fibonacci(&mut memo, &mut calls, 30));
+    assert_eq!(51, calls);
+}
@@ -0,0 +1,36 @@
+//! rayon's parallel iterators with the `parallel` feature, and plain
+//! iterators standing in for them without it, so that a day is written once
+//! for both. Import `prelude::*` in place of `rayon::prelude::*`.
+
+#[cfg(feature = "parallel")]
+pub mod prelude {
+    pub use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+}
+
+#[cfg(not(feature = "parallel"))]
+pub mod prelude {
+    pub trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<T: IntoIterator> IntoParallelIterator for T {}
+
+    pub trait IntoParallelRefIterator<'a> {
+        type Iter: Iterator;
+
+        fn par_iter(&'a self) -> Self::Iter;
+    }
+
+    impl<'a, T: 'a + ?Sized> IntoParallelRefIterator<'a> for T
+    where
+        &'a T: IntoIterator,
+    {
+        type Iter = <&'a T as IntoIterator>::IntoIter;
+
+        fn par_iter(&'a self) -> Self::Iter {
+            self.into_iter()
+        }
+    }
+}
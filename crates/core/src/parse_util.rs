@@ -0,0 +1,121 @@
+//! nom parsers more than one day is made of, along with the aliases each
+//! day's `parser` module works with.
+//!
+//! Puzzle inputs are ASCII. Parsers accept only the ASCII characters the
+//! puzzle has in mind, even where a broader class like letters would be
+//! handy, and [`crate::error::ParseError`] calls out any other character it
+//! runs into. A grid stops at the first cell it can't have, see
+//! [`Grid::parse`].
+
+use crate::grid::Grid;
+
+pub type Error<'a> = nom::error::Error<&'a str>;
+pub trait Parser<'a, T> = nom::Parser<&'a str, T, Error<'a>>;
+
+pub type ByteError<'a> = nom::error::Error<&'a [u8]>;
+pub trait ByteParser<'a, T> = nom::Parser<&'a [u8], T, ByteError<'a>>;
+
+/// Runs `parser` on the bytes of the input, which saves decoding chars on
+/// the days whose inputs are all ASCII. Where it stops is moved back to the
+/// start of a char, so that what's left is still a `&str`.
+pub fn bytes<'a, T>(mut parser: impl ByteParser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let rest_of = |rest: &[u8]| {
+            let mut offset = input.len() - rest.len();
+            while !input.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            &input[offset..]
+        };
+        match parser.parse(input.as_bytes()) {
+            Ok((rest, parsed)) => Ok((rest_of(rest), parsed)),
+            Err(err) => Err(err.map(|err| Error::new(rest_of(err.input), err.code))),
+        }
+    }
+}
+
+/// One `item` on each line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    nom::multi::separated_list1(nom::character::complete::line_ending, item)
+}
+
+/// The line endings between sections of the input, usually with a blank
+/// line among them.
+pub fn blank_lines<'a>() -> impl Parser<'a, ()> {
+    nom::multi::many1_count(nom::character::complete::line_ending).map(|_| ())
+}
+
+/// `item`s separated by spaces, like `7 6 4 2 1`.
+pub fn spaced<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    nom::multi::separated_list1(nom::character::complete::space1, item)
+}
+
+/// `item` right after `label`, like the `X+94` of a claw machine's button.
+pub fn labeled<'a, T>(label: &'static str, item: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    nom::sequence::preceded(nom::bytes::complete::tag(label), item)
+}
+
+/// A grid of the characters `cell` accepts, which should only be ASCII ones.
+pub fn char_grid<'a>(cell: impl Fn(char) -> bool) -> impl Parser<'a, Grid<char>> {
+    Grid::parse(nom::character::complete::satisfy(cell))
+}
+
+#[test]
+fn byte_parsers() {
+    let mut digits = bytes(nom::character::complete::digit1);
+    assert_eq!(Ok(("x", b"12".as_slice())), digits.parse("12x"));
+    assert_eq!(
+        Err(nom::Err::Error(Error::new(
+            "é1",
+            nom::error::ErrorKind::Digit
+        ))),
+        digits.parse("é1")
+    );
+
+    // Stopping within `é` leaves all of it.
+    let mut one_byte = bytes(nom::bytes::complete::take(1usize));
+    assert_eq!(Ok(("é", b"\xc3".as_slice())), one_byte.parse("é"));
+}
+
+#[test]
+fn lines_and_spaces() {
+    let (rest, parsed) = lines(spaced(nom::character::complete::u64))
+        .parse("1 2  3\r\n4\n")
+        .unwrap();
+    assert_eq!("\n", rest);
+    assert_eq!(vec![vec![1, 2, 3], vec![4]], parsed);
+}
+
+#[test]
+fn sections() {
+    let mut sections =
+        nom::multi::separated_list1(blank_lines(), lines(nom::character::complete::i64));
+    assert_eq!(
+        Ok(("", vec![vec![1, -2], vec![3]])),
+        sections.parse("1\n-2\n\n3")
+    );
+    assert_eq!(Ok(("", vec![vec![1, 2]])), sections.parse("1\r\n2"));
+}
+
+#[test]
+fn labels() {
+    let mut offset = nom::sequence::separated_pair(
+        labeled("X+", nom::character::complete::i64),
+        nom::bytes::complete::tag(", "),
+        labeled("Y+", nom::character::complete::i64),
+    );
+    assert_eq!(Ok(("", (94, 34))), offset.parse("X+94, Y+34"));
+    assert!(offset.parse("X=94, Y=34").is_err());
+}
+
+#[test]
+fn grids() {
+    let (rest, grid) = char_grid(|ch| ch.is_ascii_uppercase())
+        .parse("AB\nCD")
+        .unwrap();
+    assert_eq!("", rest);
+    assert_eq!(vec![vec!['A', 'B'], vec!['C', 'D']], grid.into_rows());
+    assert!(char_grid(|ch| ch.is_ascii_uppercase())
+        .parse("AB\nC")
+        .is_err());
+}
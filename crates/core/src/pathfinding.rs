@@ -0,0 +1,161 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cheapest way from any of `starts` to a node satisfying `is_goal`, as the
+/// total cost and the nodes along the way (both ends included). `successors`
+/// yields the neighbours of a node together with the cost of moving there,
+/// costs must not be negative.
+pub fn dijkstra<N, C, S, I, G>(
+    starts: impl IntoIterator<Item = N>,
+    successors: S,
+    is_goal: G,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores nodes with a low `cost + heuristic` first.
+/// `heuristic` must never overestimate the remaining cost to a goal, or the
+/// path found may not be the cheapest.
+pub fn astar<N, C, S, I, H, G>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    // Nodes are referred to by their index in `nodes`, so the heap doesn't
+    // need `N: Ord`.
+    let mut nodes: Vec<(N, C, Option<usize>)> = vec![];
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(nodes.len());
+            frontier.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push((start, C::default(), None));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        if cost > nodes[index].1 {
+            // A cheaper way here was found after this entry was queued.
+            continue;
+        }
+
+        let node = nodes[index].0.clone();
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut parent = nodes[index].2;
+            while let Some(index) = parent {
+                path.push(nodes[index].0.clone());
+                parent = nodes[index].2;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(nodes.len());
+                    nodes.push((next.clone(), next_cost, Some(index)));
+                    nodes.len() - 1
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= nodes[next_index].1 {
+                        continue;
+                    }
+                    nodes[next_index].1 = next_cost;
+                    nodes[next_index].2 = Some(index);
+                    next_index
+                }
+            };
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+
+    None
+}
+
+#[test]
+fn cheapest_path() {
+    // a -1- b -1- c, with a direct but expensive a -5- c and a detour via d.
+    let edges = [('a', 'b', 1), ('b', 'c', 1), ('a', 'c', 5), ('c', 'd', 1)];
+    let successors = |node: &char| {
+        edges
+            .iter()
+            .filter(|(from, _, _)| from == node)
+            .map(|(_, to, cost)| (*to, *cost))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        Some((2, vec!['a', 'b', 'c'])),
+        dijkstra(['a'], successors, |node| *node == 'c')
+    );
+    assert_eq!(
+        Some((0, vec!['b'])),
+        dijkstra(['a', 'b'], successors, |node| *node == 'b')
+    );
+    assert_eq!(None, dijkstra(['d'], successors, |node| *node == 'a'));
+}
+
+#[test]
+fn astar_on_grid() {
+    use crate::grid::{Grid, Position};
+
+    let grid = Grid::new(vec![
+        ".#...".chars().collect(),
+        ".#.#.".chars().collect(),
+        "...#.".chars().collect(),
+    ]);
+    let goal = Position::new(0, 4);
+    let successors = |position: &Position| {
+        grid.neighbor_cells4(*position)
+            .filter(|(_, cell)| **cell == '.')
+            .map(|(position, _)| (position, 1))
+            .collect::<Vec<_>>()
+    };
+    let manhattan = |position: &Position| {
+        position.row_index.abs_diff(goal.row_index) + position.col_index.abs_diff(goal.col_index)
+    };
+
+    let (cost, path) = astar([Position::new(0, 0)], successors, manhattan, |position| {
+        *position == goal
+    })
+    .unwrap();
+
+    assert_eq!(8, cost);
+    assert_eq!(9, path.len());
+    assert_eq!(
+        Some((cost, path.len())),
+        dijkstra([Position::new(0, 0)], successors, |position| *position
+            == goal)
+        .map(|(cost, path)| (cost, path.len()))
+    );
+}
@@ -0,0 +1,37 @@
+use std::{cell::Cell, time::Duration};
+
+thread_local! {
+    static PARSING: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// Runs `parse` and counts the time it takes as parsing, so that callers
+/// timing a whole solution can tell parsing and solving apart.
+///
+/// There's no clock on `wasm32-unknown-unknown`, where this only runs
+/// `parse`.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub fn parsing<T>(parse: impl FnOnce() -> T) -> T {
+    let start = std::time::Instant::now();
+    let parsed = parse();
+    PARSING.with(|parsing| parsing.set(parsing.get() + start.elapsed()));
+    parsed
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub fn parsing<T>(parse: impl FnOnce() -> T) -> T {
+    parse()
+}
+
+/// Time spent in [`parsing`] on this thread since the last call.
+pub fn take_parsing_time() -> Duration {
+    PARSING.with(Cell::take)
+}
+
+#[test]
+fn accumulates_until_taken() {
+    take_parsing_time();
+    parsing(|| std::thread::sleep(Duration::from_millis(2)));
+    parsing(|| std::thread::sleep(Duration::from_millis(2)));
+    assert!(take_parsing_time() >= Duration::from_millis(4));
+    assert_eq!(Duration::ZERO, take_parsing_time());
+}
@@ -0,0 +1,90 @@
+//! Every day behind one trait, for callers that only know which day to run
+//! at runtime. The list is generated from the day modules, so a new
+//! `day_N.rs` with a `TITLE`, `solution` and `validate` is all it takes to
+//! show up here.
+
+use crate::{day::Day, error::SolveError, validate::Report};
+
+/// A day of the puzzle, with its answers turned into JSON so that every day
+/// has the same type.
+pub trait Solver: Sync {
+    fn day(&self) -> Day;
+
+    /// The puzzle's title on adventofcode.com.
+    fn title(&self) -> &'static str;
+
+    /// The answer as an object with a field per part, like `part_1`.
+    fn solve(&self, input: &str) -> Result<serde_json::Value, SolveError>;
+
+    fn validate(&self, input: &str) -> Result<Report, SolveError>;
+}
+
+/// A day module's functions, see [`SOLVERS`].
+struct Module<A> {
+    number: u8,
+    title: &'static str,
+    solution: fn(&str) -> Result<A, SolveError>,
+    validate: fn(&str) -> Result<Report, SolveError>,
+}
+
+impl<A: serde::Serialize> Solver for Module<A> {
+    fn day(&self) -> Day {
+        Day::new(self.number)
+    }
+
+    fn title(&self) -> &'static str {
+        self.title
+    }
+
+    fn solve(&self, input: &str) -> Result<serde_json::Value, SolveError> {
+        let answer = (self.solution)(input)?;
+        Ok(serde_json::to_value(answer).expect("answers are structs of answer values"))
+    }
+
+    fn validate(&self, input: &str) -> Result<Report, SolveError> {
+        (self.validate)(input)
+    }
+}
+
+macro_rules! solvers {
+    ($(($number:literal, $module:ident)),* $(,)?) => {
+        /// Every implemented day, in order.
+        pub static SOLVERS: &[&dyn Solver] = &[$(
+            &Module {
+                number: $number,
+                title: crate::$module::TITLE,
+                solution: crate::$module::solution,
+                validate: crate::$module::validate,
+            },
+        )*];
+    };
+}
+
+with_days!(solvers);
+
+/// The solver of `day`, `None` if it isn't implemented.
+pub fn solver(day: Day) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().copied().find(|solver| solver.day() == day)
+}
+
+#[test]
+fn every_day() {
+    assert_eq!(
+        crate::IMPLEMENTED_DAYS,
+        SOLVERS
+            .iter()
+            .map(|solver| solver.day())
+            .collect::<Vec<_>>()
+    );
+    assert!(solver(Day::new(25)).is_none());
+
+    let day_1 = solver(Day::new(1)).unwrap();
+    assert_eq!("Historian Hysteria", day_1.title());
+    assert_eq!(
+        serde_json::json!({ "part_1": 11, "part_2": 31 }),
+        day_1
+            .solve("3   4\n4   3\n2   5\n1   3\n3   9\n3   3")
+            .unwrap()
+    );
+    assert!(day_1.validate("3   4\n4").is_err());
+}
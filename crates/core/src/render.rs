@@ -0,0 +1,254 @@
+//! Pictures of grids with a square of pixels for every cell, as a PNG of one
+//! grid or an animated GIF of one after the other. Needs the `render`
+//! feature.
+//!
+//! Days that hold on to a [`Grid`], like the garden of day 12 or the maze of
+//! day 16, can draw it with [`rasterize`] and a color for each kind of cell.
+//! [`Images`] draws the text frames of any visualization instead, the guard
+//! of day 6 and the robots of day 14 included.
+
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use image::{
+    buffer::ConvertBuffer,
+    codecs::{
+        gif::{GifEncoder, Repeat},
+        png::PngEncoder,
+    },
+    Delay, ExtendedColorType, Frame, ImageEncoder, ImageError, RgbImage,
+};
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, GridSize},
+    viz,
+};
+
+pub use image::Rgb;
+
+/// Draws every cell of `grid` as a `scale` by `scale` square in the color
+/// `color` picks for it.
+pub fn rasterize<T>(grid: &Grid<T>, scale: u32, color: impl FnMut(&T) -> Rgb<u8>) -> RgbImage {
+    let GridSize(rows, cols) = grid.size();
+    let colors = grid
+        .rows()
+        .flat_map(|row| row.iter())
+        .map(color)
+        .collect_vec();
+    RgbImage::from_fn(cols as u32 * scale, rows as u32 * scale, |x, y| {
+        colors[(y / scale) as usize * cols + (x / scale) as usize]
+    })
+}
+
+pub fn write_png<W: Write>(image: &RgbImage, writer: W) -> io::Result<()> {
+    PngEncoder::new(writer)
+        .write_image(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            ExtendedColorType::Rgb8,
+        )
+        .map_err(io_error)
+}
+
+/// Encoding mostly fails on writing, and the rest goes along as an I/O error
+/// too, the way [`viz::Sink`] reports errors.
+fn io_error(err: ImageError) -> io::Error {
+    match err {
+        ImageError::IoError(err) => err,
+        err => io::Error::other(err),
+    }
+}
+
+/// An animated GIF that loops forever, written a frame at a time.
+pub struct Gif<W: Write> {
+    encoder: GifEncoder<W>,
+    delay: Delay,
+}
+
+impl<W: Write> Gif<W> {
+    /// Shows every frame for `delay`.
+    pub fn new(writer: W, delay: Duration) -> io::Result<Self> {
+        // Quantizing the colors is most of the work, and the frames only have
+        // a handful of them anyway.
+        let mut encoder = GifEncoder::new_with_speed(writer, 10);
+        encoder.set_repeat(Repeat::Infinite).map_err(io_error)?;
+        Ok(Self {
+            encoder,
+            delay: Delay::from_saturating_duration(delay),
+        })
+    }
+
+    pub fn frame(&mut self, image: &RgbImage) -> io::Result<()> {
+        self.encoder
+            .encode_frame(Frame::from_parts(image.convert(), 0, 0, self.delay))
+            .map_err(io_error)
+    }
+}
+
+/// The colors of the tiles in the puzzles' notation: `.` is empty, `#` a
+/// wall, digits are heights and arrows are whoever is walking around.
+pub fn tile_color(tile: char) -> Rgb<u8> {
+    match tile {
+        '.' => Rgb([15, 15, 35]),
+        '#' => Rgb([120, 120, 120]),
+        '^' | '>' | 'v' | '<' => Rgb([255, 255, 102]),
+        'O' => Rgb([230, 60, 60]),
+        '|' | '-' | '+' => Rgb([200, 200, 200]),
+        '0'..='9' => {
+            let height = tile as u8 - b'0';
+            Rgb([0, 60 + height * 20, 40])
+        }
+        tile if tile.is_ascii_alphanumeric() => {
+            // The same 6x6x6 color cube as the 256 ANSI colors.
+            let idx = viz::color_cube_index(tile as usize);
+            let level = |value: u8| value * 40 + 55;
+            Rgb([level(idx / 36), level(idx / 6 % 6), level(idx % 6)])
+        }
+        _ => Rgb([255, 255, 255]),
+    }
+}
+
+/// The grid in a text frame, which is the longest run of lines of the same
+/// width. Headings and notes around the grid are left out that way.
+pub fn text_grid(frame: &str) -> Grid<char> {
+    let lines = frame.lines().collect_vec();
+    let (start, len) = lines
+        .iter()
+        .enumerate()
+        .chunk_by(|(_, line)| line.chars().count())
+        .into_iter()
+        .filter(|(width, _)| *width > 0)
+        .map(|(_, run)| {
+            let run = run.collect_vec();
+            (run[0].0, run.len())
+        })
+        // The first of the longest runs.
+        .fold(
+            (0, 0),
+            |longest, run| {
+                if run.1 > longest.1 {
+                    run
+                } else {
+                    longest
+                }
+            },
+        );
+    Grid::new(
+        lines[start..start + len]
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect(),
+    )
+}
+
+/// Draws the text frames of a visualization with [`tile_color`], which need
+/// to be without ANSI colors. A PNG shows the last frame once the
+/// visualization is done and [`Images::finish`] is called, a GIF every frame.
+pub enum Images<W: Write> {
+    Png {
+        writer: W,
+        scale: u32,
+        last: Option<Grid<char>>,
+    },
+    Gif {
+        gif: Gif<W>,
+        scale: u32,
+    },
+}
+
+impl<W: Write> Images<W> {
+    pub fn png(writer: W, scale: u32) -> Self {
+        Images::Png {
+            writer,
+            scale,
+            last: None,
+        }
+    }
+
+    pub fn gif(writer: W, scale: u32, delay: Duration) -> io::Result<Self> {
+        Ok(Images::Gif {
+            gif: Gif::new(writer, delay)?,
+            scale,
+        })
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        match self {
+            Images::Png {
+                writer,
+                scale,
+                last,
+            } => {
+                let last = last.ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "there's no frame to save")
+                })?;
+                write_png(&rasterize(&last, scale, |tile| tile_color(*tile)), writer)
+            }
+            // The GIF ends once it's dropped.
+            Images::Gif { .. } => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> viz::Sink for Images<W> {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        let grid = text_grid(frame);
+        match self {
+            Images::Png { last, .. } => {
+                *last = Some(grid);
+                Ok(())
+            }
+            Images::Gif { gif, scale } => {
+                gif.frame(&rasterize(&grid, *scale, |tile| tile_color(*tile)))
+            }
+        }
+    }
+}
+
+#[test]
+fn rasterizing() {
+    let grid = Grid::new(vec![vec![true, false], vec![false, false]]);
+    let image = rasterize(&grid, 2, |cell| Rgb([*cell as u8 * 255, 0, 0]));
+    assert_eq!((4, 4), image.dimensions());
+    assert_eq!(Rgb([255, 0, 0]), image[(1, 1)]);
+    assert_eq!(Rgb([0, 0, 0]), image[(2, 1)]);
+    assert_eq!(Rgb([0, 0, 0]), image[(1, 2)]);
+
+    let mut png = vec![];
+    write_png(&image, &mut png).unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+}
+
+#[test]
+fn text_grids() {
+    assert_eq!(
+        Grid::new(vec![vec!['.', '#'], vec!['#', '.']]),
+        text_grid("After 1 seconds:\n.#\n#.\n\nsome notes")
+    );
+    assert_eq!(
+        Grid::new(vec!["00...111".chars().collect()]),
+        text_grid("00...111")
+    );
+}
+
+#[test]
+fn images() {
+    use viz::Sink;
+
+    let mut gif = vec![];
+    let mut images = Images::gif(&mut gif, 1, Duration::from_millis(100)).unwrap();
+    images.frame("0.1").unwrap();
+    images.frame("01.").unwrap();
+    images.finish().unwrap();
+    assert!(gif.starts_with(b"GIF89a"));
+
+    let mut png = vec![];
+    let mut images = Images::png(&mut png, 1);
+    images.frame("0.1").unwrap();
+    images.finish().unwrap();
+    assert!(png.starts_with(b"\x89PNG"));
+}
@@ -0,0 +1,106 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Visits every node reachable from `start` exactly once, nearest first.
+/// `visit` gets each node together with its distance in steps from `start`.
+pub fn bfs<N, S, I, V>(start: N, mut successors: S, mut visit: V)
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: FnMut(&N, usize),
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        visit(&node, distance);
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+}
+
+/// Visits every node reachable from `seed` exactly once, in no particular
+/// order. Cheaper than [`bfs`] when distances don't matter.
+pub fn flood_fill<N, S, I, V>(seed: N, mut successors: S, mut visit: V)
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: FnMut(&N),
+{
+    let mut seen = HashSet::from([seed.clone()]);
+    let mut stack = vec![seed];
+
+    while let Some(node) = stack.pop() {
+        visit(&node);
+        stack.extend(
+            successors(&node)
+                .into_iter()
+                .filter(|next| seen.insert(next.clone())),
+        );
+    }
+}
+
+#[test]
+fn bfs_distances() {
+    // 0 - 1 - 2 - 3, with a shortcut 0 - 3 and an unreachable 4.
+    let edges = [(0, 1), (1, 2), (2, 3), (0, 3), (4, 0)];
+    let mut distances = vec![None; 5];
+
+    bfs(
+        0,
+        |node| {
+            edges
+                .iter()
+                .filter_map(|(from, to)| (from == node).then_some(*to))
+                .collect::<Vec<_>>()
+        },
+        |node, distance| {
+            assert!(distances[*node].replace(distance).is_none());
+        },
+    );
+
+    assert_eq!(vec![Some(0), Some(1), Some(2), Some(1), None], distances);
+}
+
+#[test]
+fn flood_fill_region() {
+    use crate::grid::{Grid, Position};
+
+    let grid = Grid::new(vec![
+        "aab".chars().collect(),
+        "abb".chars().collect(),
+        "aaa".chars().collect(),
+    ]);
+    let mut region = vec![];
+
+    flood_fill(
+        Position::new(0, 0),
+        |position| {
+            grid.neighbor_cells4(*position)
+                .filter(|(_, cell)| **cell == 'a')
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>()
+        },
+        |position| region.push(*position),
+    );
+
+    region.sort();
+    assert_eq!(
+        vec![
+            Position::new(0, 0),
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(2, 0),
+            Position::new(2, 1),
+            Position::new(2, 2),
+        ],
+        region
+    );
+}
@@ -0,0 +1,116 @@
+//! Proptest strategies for structurally valid puzzle inputs, small enough to
+//! shrink well, so solvers can be checked for invariants beyond the examples.
+
+use itertools::Itertools;
+use proptest::{collection::vec, prelude::*};
+
+/// Day 2: lines of space separated levels.
+pub fn reports() -> impl Strategy<Value = String> {
+    vec(vec(1i64..20, 1..8), 1..20).prop_map(|reports| {
+        reports
+            .into_iter()
+            .map(|levels| levels.iter().join(" "))
+            .join("\n")
+    })
+}
+
+/// Day 6: a map with obstructions and exactly one guard. The guard may well
+/// be stuck in a loop right away.
+pub fn guard_map() -> impl Strategy<Value = String> {
+    (1usize..12, 1usize..12)
+        .prop_flat_map(|(rows, cols)| {
+            (
+                vec(vec(prop::bool::weighted(0.15), cols), rows),
+                0..rows,
+                0..cols,
+                prop::sample::select(vec!['^', '>', 'v', '<']),
+            )
+        })
+        .prop_map(|(obstructions, guard_row, guard_col, guard)| {
+            obstructions
+                .into_iter()
+                .enumerate()
+                .map(|(row, cells)| {
+                    cells
+                        .into_iter()
+                        .enumerate()
+                        .map(|(col, obstructed)| match obstructed {
+                            _ if (row, col) == (guard_row, guard_col) => guard,
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+}
+
+/// Day 7: calibration equations of a few small numbers. The test value is
+/// either their sum, so at least one way of putting operators works, or made
+/// up.
+pub fn equations() -> impl Strategy<Value = String> {
+    vec((vec(0i64..30, 1..6), prop::option::of(0i64..5_000)), 1..10).prop_map(|equations| {
+        equations
+            .into_iter()
+            .map(|(nums, target)| {
+                let target = target.unwrap_or_else(|| nums.iter().sum());
+                format!("{target}: {}", nums.iter().join(" "))
+            })
+            .join("\n")
+    })
+}
+
+/// Day 9: a disk map of non-empty files and gaps of any size.
+pub fn disk_map() -> impl Strategy<Value = String> {
+    vec((1u8..=9, 0u8..=9), 1..30).prop_map(|fragments| {
+        let mut digits = fragments
+            .into_iter()
+            .flat_map(|(file, gap)| [file, gap])
+            .map(|digit| char::from(b'0' + digit))
+            .collect::<String>();
+        // The map ends in a file.
+        digits.pop();
+        digits
+    })
+}
+
+/// Day 11: a few stones, as numbers rather than text so that the solvers can
+/// blink them any number of times.
+pub fn stones() -> impl Strategy<Value = Vec<u64>> {
+    vec(prop_oneof![0u64..10, 0u64..100_000, 0u64..1 << 40], 1..6)
+}
+
+/// Day 13: how far buttons A and B move the claw, never in the same
+/// direction.
+fn buttons() -> impl Strategy<Value = ((i64, i64), (i64, i64))> {
+    ((1i64..100, 1i64..100), (1i64..100, 1i64..100))
+        .prop_filter("buttons must not be parallel", |((ax, ay), (bx, by))| {
+            ax * by != ay * bx
+        })
+}
+
+/// Day 13: a single claw machine and the tokens its prize takes when it is
+/// won with `a` and `b` presses, each at most 100.
+pub fn winnable_claw_machine() -> impl Strategy<Value = (String, u128)> {
+    (buttons(), 0i64..=100, 0i64..=100).prop_map(|(((ax, ay), (bx, by)), a, b)| {
+        let machine = claw_machine((ax, ay), (bx, by), (a * ax + b * bx, a * ay + b * by));
+        (machine, 3 * a as u128 + b as u128)
+    })
+}
+
+/// Day 13: claw machines with arbitrary prizes, most of them can't be won.
+pub fn claw_machines() -> impl Strategy<Value = String> {
+    vec((buttons(), (0i64..20_000, 0i64..20_000)), 1..6).prop_map(|machines| {
+        machines
+            .into_iter()
+            .map(|((a, b), prize)| claw_machine(a, b, prize))
+            .join("\n\n")
+    })
+}
+
+fn claw_machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> String {
+    format!(
+        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+        a.0, a.1, b.0, b.1, prize.0, prize.1
+    )
+}
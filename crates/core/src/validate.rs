@@ -0,0 +1,132 @@
+//! Checks on a puzzle input short of solving it: every day's `validate`
+//! parses the input and looks for what its solution takes for granted, like
+//! a grid having a single guard, so that a corrupted paste can be told apart
+//! from a wrong answer.
+
+use std::fmt::{self, Display};
+
+/// What `validate` found out about an input.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct Report {
+    /// The shape of the input, like how many lines of what it has.
+    pub notes: Vec<String>,
+    /// What would make solving fail or the answer wrong.
+    pub problems: Vec<String>,
+}
+
+impl Report {
+    pub fn note(&mut self, note: impl Display) {
+        self.notes.push(note.to_string());
+    }
+
+    pub fn problem(&mut self, problem: impl Display) {
+        self.problems.push(problem.to_string());
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for note in &self.notes {
+            writeln!(f, "{note}")?;
+        }
+        for problem in &self.problems {
+            writeln!(f, "problem: {problem}")?;
+        }
+        match self.problems.len() {
+            0 => write!(f, "the input looks fine"),
+            1 => write!(f, "1 problem found"),
+            problems => write!(f, "{problems} problems found"),
+        }
+    }
+}
+
+/// Notes how many rows of how many cells the grid in `input` has, or why it
+/// isn't rectangular. Grid parsers only say that it isn't, so this is worth
+/// checking before parsing one; `false` means there's no point to it. Empty
+/// lines at the end don't count as rows.
+pub fn grid_shape(input: &str, report: &mut Report) -> bool {
+    let mut lines: Vec<_> = input.lines().collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut rows = lines.iter();
+    let Some(first) = rows.next().filter(|row| !row.is_empty()) else {
+        report.problem("the grid is empty");
+        return false;
+    };
+
+    let cols = first.chars().count();
+    let mut rectangular = true;
+    for (idx, row) in rows.enumerate() {
+        let len = row.chars().count();
+        if len != cols {
+            report.problem(format_args!(
+                "line {} has {len} cells, the lines above have {cols}",
+                idx + 2
+            ));
+            rectangular = false;
+        }
+    }
+    if rectangular {
+        report.note(format_args!("{} rows of {cols} cells", lines.len()));
+    }
+    rectangular
+}
+
+/// Adds a problem unless `input` has exactly one of `what`, which `is` tells
+/// apart.
+pub fn exactly_one(input: &str, what: &str, is: impl Fn(char) -> bool, report: &mut Report) {
+    let count = input.chars().filter(|ch| is(*ch)).count();
+    if count != 1 {
+        report.problem(format_args!("expected exactly one {what}, found {count}"));
+    }
+}
+
+#[test]
+fn grids() {
+    let mut report = Report::default();
+    assert!(grid_shape("ab\ncd\n", &mut report));
+    assert_eq!(vec!["2 rows of 2 cells"], report.notes);
+    assert!(report.is_valid());
+
+    let mut report = Report::default();
+    assert!(!grid_shape("abc\nab\r\nabc\nabcd", &mut report));
+    assert_eq!(
+        vec![
+            "line 2 has 2 cells, the lines above have 3",
+            "line 4 has 4 cells, the lines above have 3"
+        ],
+        report.problems
+    );
+
+    assert!(!grid_shape("\nab", &mut Report::default()));
+    assert!(!grid_shape("\n\n", &mut Report::default()));
+}
+
+#[test]
+fn trailing_empty_lines() {
+    let mut report = Report::default();
+    assert!(grid_shape("ab\ncd\n\n\n", &mut report));
+    assert_eq!(vec!["2 rows of 2 cells"], report.notes);
+    assert!(report.is_valid());
+}
+
+#[test]
+fn counting() {
+    let mut report = Report::default();
+    exactly_one("S..\n.E.", "start", |ch| ch == 'S', &mut report);
+    exactly_one("S..\n.E.", "guard", |ch| ch == '^', &mut report);
+    exactly_one("^.^", "guard", |ch| ch == '^', &mut report);
+    assert_eq!(
+        vec![
+            "expected exactly one guard, found 0",
+            "expected exactly one guard, found 2"
+        ],
+        report.problems
+    );
+}
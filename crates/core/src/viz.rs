@@ -0,0 +1,204 @@
+use std::{
+    io::{self, BufRead, Write},
+    thread::sleep,
+    time::Duration,
+};
+
+use anstyle::Ansi256Color;
+
+use crate::error::{ParseError, SolveError};
+
+/// What a day's visualization should show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub part: u8,
+    /// Whether frames may contain ANSI colors.
+    pub color: bool,
+}
+
+/// Why a visualization stopped before it was done.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// There's nothing to show for the input.
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+    /// A frame couldn't be shown or saved.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Solve(err.into())
+    }
+}
+
+/// Spreads `id` over the 6x6x6 color cube of the 256 ANSI colors, so that
+/// neighboring ids get colors that are easy to tell apart.
+pub fn color_cube_index(id: usize) -> u8 {
+    (id * 37 % 216) as u8
+}
+
+/// The color for `id` in frames, from the color cube. Skips the 16 system
+/// colors before it and the grays after it.
+pub fn id_color(id: usize) -> Ansi256Color {
+    Ansi256Color(16 + color_cube_index(id))
+}
+
+/// Where the frames of a visualization go, one call per step of the
+/// solution.
+pub trait Sink {
+    fn frame(&mut self, frame: &str) -> io::Result<()>;
+}
+
+/// Keeps every frame, handy for tests.
+impl Sink for Vec<String> {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        self.push(frame.to_owned());
+        Ok(())
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        (**self).frame(frame)
+    }
+}
+
+/// Passes on only the first of every `n` frames. Call [`Every::finish`] at the
+/// end so that the last frame isn't left out, it's the one showing how things
+/// turned out.
+#[derive(Debug)]
+pub struct Every<S> {
+    n: usize,
+    seen: usize,
+    skipped: Option<String>,
+    sink: S,
+}
+
+impl<S: Sink> Every<S> {
+    pub fn new(n: usize, sink: S) -> Self {
+        assert!(n > 0, "can't pass on every 0th frame");
+        Self {
+            n,
+            seen: 0,
+            skipped: None,
+            sink,
+        }
+    }
+
+    /// Passes on the last frame if it was skipped.
+    pub fn finish(mut self) -> io::Result<S> {
+        if let Some(frame) = self.skipped.take() {
+            self.sink.frame(&frame)?;
+        }
+        Ok(self.sink)
+    }
+}
+
+impl<S: Sink> Sink for Every<S> {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        let first_of_n = self.seen.is_multiple_of(self.n);
+        self.seen += 1;
+        if first_of_n {
+            self.skipped = None;
+            self.sink.frame(frame)
+        } else {
+            self.skipped = Some(frame.to_owned());
+            Ok(())
+        }
+    }
+}
+
+/// Writes frames one after the other with an empty line between them, to
+/// look through or diff once the visualization is done.
+#[derive(Debug)]
+pub struct Trace<W> {
+    writer: W,
+    frames: usize,
+}
+
+impl<W: Write> Trace<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, frames: 0 }
+    }
+}
+
+impl<W: Write> Sink for Trace<W> {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        if self.frames > 0 {
+            writeln!(self.writer)?;
+        }
+        writeln!(self.writer, "{frame}")?;
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+/// How long [`Terminal`] waits after each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pause {
+    None,
+    Delay(Duration),
+    /// Until Enter is pressed.
+    Step,
+}
+
+/// Prints frames to stdout. Single line frames are printed one below the
+/// other like the examples in the puzzle texts, taller ones redraw the
+/// screen.
+#[derive(Debug)]
+pub struct Terminal {
+    pause: Pause,
+}
+
+impl Terminal {
+    pub fn new(pause: Pause) -> Self {
+        Self { pause }
+    }
+}
+
+impl Sink for Terminal {
+    fn frame(&mut self, frame: &str) -> io::Result<()> {
+        let mut stdout = anstream::stdout().lock();
+        if frame.contains('\n') {
+            // Clear the screen and move the cursor to the top left.
+            write!(stdout, "\x1b[2J\x1b[H")?;
+        }
+        writeln!(stdout, "{frame}")?;
+        stdout.flush()?;
+
+        match self.pause {
+            Pause::None => {}
+            Pause::Delay(delay) => sleep(delay),
+            Pause::Step => {
+                io::stdin().lock().read_line(&mut String::new())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn every() {
+    let frames = ["0", "1", "2", "3", "4", "5"];
+    let sink = |n, count| {
+        let mut every = Every::new(n, Vec::new());
+        frames[..count]
+            .iter()
+            .for_each(|frame| every.frame(frame).unwrap());
+        every.finish().unwrap()
+    };
+    assert_eq!(frames.to_vec(), sink(1, 6));
+    assert_eq!(vec!["0", "2", "4", "5"], sink(2, 6));
+    assert_eq!(vec!["0", "2", "4"], sink(2, 5));
+    assert_eq!(vec!["0", "5"], sink(10, 6));
+}
+
+#[test]
+fn trace() {
+    let mut trace = Trace::new(Vec::new());
+    trace.frame("0.1").unwrap();
+    trace.frame("01.\n...").unwrap();
+    assert_eq!(b"0.1\n\n01.\n...\n", trace.writer.as_slice());
+}
@@ -0,0 +1,62 @@
+//! JavaScript bindings with the `wasm` feature, for solving in a browser.
+//! Build without the default `parallel` feature, as there are no threads to
+//! run rayon on:
+//!
+//! ```sh
+//! cargo rustc -p aoc-2024-core --release --target wasm32-unknown-unknown \
+//!     --no-default-features --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/aoc_2024_core.wasm
+//! ```
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    day::Day,
+    registry::{self, Solver},
+};
+
+fn solver(day: u8) -> Result<&'static dyn Solver, JsError> {
+    let day = Day::try_from(day)?;
+    registry::solver(day).ok_or_else(|| JsError::new(&format!("{day} isn't implemented")))
+}
+
+/// Plain objects rather than the `Map`s `serde_wasm_bindgen` makes by
+/// default, so that the result reads like the JSON the CLI prints.
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsError> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+/// The answer to `day` for `input`, as an object with a field per part like
+/// `{ part_1: 11, part_2: 31 }`. Throws if the day isn't implemented or the
+/// input doesn't parse.
+#[wasm_bindgen]
+pub fn solve(day: u8, input: &str) -> Result<JsValue, JsError> {
+    to_js(&solver(day)?.solve(input)?)
+}
+
+/// What `validate` finds out about `input`, as `{ notes, problems }`.
+#[wasm_bindgen]
+pub fn validate(day: u8, input: &str) -> Result<JsValue, JsError> {
+    to_js(&solver(day)?.validate(input)?)
+}
+
+/// Every implemented day as `{ day, title }`.
+#[wasm_bindgen]
+pub fn days() -> Result<JsValue, JsError> {
+    #[derive(Serialize)]
+    struct Entry {
+        day: u8,
+        title: &'static str,
+    }
+
+    let days: Vec<_> = registry::SOLVERS
+        .iter()
+        .map(|solver| Entry {
+            day: solver.day().number(),
+            title: solver.title(),
+        })
+        .collect();
+    to_js(&days)
+}
@@ -0,0 +1,34 @@
+//! What the test files that go over every day have in common.
+
+/// A `#[test]` per day that passes the day and its `solution` to the
+/// `check` function of the test file, which takes
+/// `(Day, fn(&str) -> Result<A, SolveError>)`. Called back by
+/// `aoc_2024_core::with_days!` with every day, or with the days to test and
+/// an attribute for every test in front of them.
+macro_rules! day_tests {
+    (#[$attr:meta] $(($number:literal, $module:ident)),* $(,)?) => {
+        $(
+            #[test]
+            #[$attr]
+            fn $module() {
+                check(
+                    aoc_2024_core::day::Day::new($number),
+                    aoc_2024_core::$module::solution,
+                );
+            }
+        )*
+    };
+    ($(($number:literal, $module:ident)),* $(,)?) => {
+        $(
+            #[test]
+            fn $module() {
+                check(
+                    aoc_2024_core::day::Day::new($number),
+                    aoc_2024_core::$module::solution,
+                );
+            }
+        )*
+    };
+}
+
+pub(crate) use day_tests;
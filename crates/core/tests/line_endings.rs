@@ -0,0 +1,38 @@
+//! Inputs saved on Windows end lines in `\r\n`, and downloaded ones end in a
+//! newline the examples don't have. Every day should answer the same either
+//! way.
+
+use aoc_2024_core::{day::Day, error::SolveError, examples};
+use common::day_tests;
+
+mod common;
+
+fn check<A: serde::Serialize>(day: Day, solution: fn(&str) -> Result<A, SolveError>) {
+    let answer = |input: &str| {
+        serde_json::to_value(
+            solution(input).unwrap_or_else(|err| panic!("{day} fails on {input:?}: {err}")),
+        )
+        .unwrap()
+    };
+
+    for (variant, input) in examples::all(day) {
+        let input = input.trim_end();
+        let expected = answer(input);
+        for line_ending in ["\n", "\r\n"] {
+            let lines = input.lines().collect::<Vec<_>>().join(line_ending);
+            assert_eq!(
+                expected,
+                answer(&lines),
+                "{day} {variant} with {line_ending:?}"
+            );
+            let terminated = lines + line_ending;
+            assert_eq!(
+                expected,
+                answer(&terminated),
+                "{day} {variant} ending in {line_ending:?}"
+            );
+        }
+    }
+}
+
+aoc_2024_core::with_days!(day_tests);
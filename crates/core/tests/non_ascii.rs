@@ -0,0 +1,40 @@
+//! Inputs are ASCII, and a character that isn't, say a letter with an accent
+//! or a zero-width space from a careless paste, is rejected right where it is
+//! rather than taken for a cell or cutting the input short.
+
+use aoc_2024_core::{day::Day, error::SolveError, examples};
+use common::day_tests;
+
+mod common;
+
+fn check<A>(day: Day, solution: fn(&str) -> Result<A, SolveError>) {
+    let example = examples::text(day, examples::all(day).next().unwrap().0);
+    for unexpected in ['é', 'Ａ', '٣', '\u{200b}'] {
+        // The third cell of the second row.
+        let mut lines = example.lines().map(str::to_owned).collect::<Vec<_>>();
+        lines[1].replace_range(2..3, &unexpected.to_string());
+        let input = lines.join("\n");
+
+        match solution(&input) {
+            Err(SolveError::Parse(err)) => {
+                assert_eq!((2, 3), (err.line, err.column), "{day} with {unexpected:?}");
+                assert!(
+                    err.message.contains("non-ASCII"),
+                    "{day} with {unexpected:?}: {}",
+                    err.message
+                );
+            }
+            Err(err) => panic!("{day} with {unexpected:?} fails with {err}"),
+            Ok(_) => panic!("{day} with {unexpected:?} solves"),
+        }
+    }
+}
+
+day_tests!(
+    (4, day_4),
+    (6, day_6),
+    (8, day_8),
+    (10, day_10),
+    (12, day_12),
+    (16, day_16)
+);
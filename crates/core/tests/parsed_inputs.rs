@@ -0,0 +1,62 @@
+//! The parsed inputs days hand out, used the way a tool outside of the crate
+//! would, without solving anything.
+
+use aoc_2024_core::{
+    day::Day,
+    day_13::{self, Button, Prize},
+    day_14, day_6, day_9, examples,
+    grid::{Direction, Position},
+};
+
+#[test]
+fn day_6() {
+    let input = day_6::parse(examples::text(Day::new(6), "example")).unwrap();
+    assert_eq!(Direction::Up, input.guard_initial_direction);
+    assert_eq!(
+        day_6::Position {
+            row_index: 6,
+            col_index: 4
+        },
+        input.guard_initial_position
+    );
+    assert_eq!(day_6::Cell::Obstruction, input.map[0][4]);
+}
+
+#[test]
+fn day_9() {
+    let fragments = day_9::parse("12345").unwrap();
+    assert_eq!(5, fragments.len());
+    assert_eq!(
+        "0..111....22222",
+        fragments
+            .iter()
+            .flat_map(day_9::Fragment::blocks)
+            .map(|block| match block {
+                day_9::Block::Free => '.',
+                day_9::Block::File { id } => char::from_digit(id as u32, 10).unwrap(),
+            })
+            .collect::<String>()
+    );
+}
+
+#[test]
+fn day_13() {
+    let machines = day_13::parse(examples::text(Day::new(13), "example")).unwrap();
+    assert_eq!(4, machines.len());
+    assert_eq!(
+        Button {
+            x_offset: 94,
+            y_offset: 34
+        },
+        machines[0].button_a
+    );
+    assert_eq!(Prize { x: 8400, y: 5400 }, machines[0].prize);
+}
+
+#[test]
+fn day_14() {
+    let robots = day_14::parse(examples::text(Day::new(14), "example")).unwrap();
+    assert_eq!(12, robots.len());
+    assert_eq!(Position::new(4, 0), robots[0].current_position);
+    assert!(day_14::parse("p=0,4 v=3").is_err());
+}
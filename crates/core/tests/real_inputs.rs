@@ -0,0 +1,85 @@
+//! Every day on real puzzle inputs, which can't be shared and so aren't in
+//! the repository. Ignored by default, run them with
+//!
+//! ```sh
+//! AOC_INPUT_DIR=path/to/inputs cargo test -p aoc-2024-core --test real_inputs -- --ignored
+//! ```
+//!
+//! The directory (`inputs` at the root of the workspace by default) holds
+//! `day1.txt`, `day2.txt` and so on, plus an `expected_answers.toml` with the
+//! answers adventofcode.com accepted:
+//!
+//! ```toml
+//! [day1]
+//! part_1 = 1234
+//! part_2 = 5678
+//! ```
+//!
+//! Days without an input or without expected answers are skipped.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_2024_core::{day::Day, error::SolveError};
+use common::day_tests;
+
+mod common;
+
+fn input_dir() -> PathBuf {
+    env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../inputs"))
+}
+
+/// The expected answers for `day`, each part as text.
+fn expected_answers(dir: &Path, day: Day) -> Option<toml::Table> {
+    let path = dir.join("expected_answers.toml");
+    let text = fs::read_to_string(&path).ok()?;
+    let mut answers: toml::Table =
+        toml::from_str(&text).unwrap_or_else(|err| panic!("{} isn't valid: {err}", path.display()));
+    match answers.remove(&day.to_string())? {
+        toml::Value::Table(parts) => Some(parts),
+        _ => panic!("`{day}` in {} isn't a table", path.display()),
+    }
+}
+
+fn as_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn check<A: serde::Serialize>(day: Day, solution: fn(&str) -> Result<A, SolveError>) {
+    let dir = input_dir();
+    let input_path = dir.join(format!("{day}.txt"));
+    let Ok(input) = fs::read_to_string(&input_path) else {
+        eprintln!("skipping {day}, {} can't be read", input_path.display());
+        return;
+    };
+    let Some(expected) = expected_answers(&dir, day) else {
+        eprintln!("skipping {day}, no expected answers");
+        return;
+    };
+
+    let answer = serde_json::to_value(solution(&input).unwrap()).unwrap();
+    for (part, expected) in &expected {
+        let actual = match answer.get(part) {
+            Some(serde_json::Value::String(string)) => string.clone(),
+            Some(value) => value.to_string(),
+            None => panic!("{day} has no {part}"),
+        };
+        assert_eq!(as_text(expected), actual, "{day} {part}");
+    }
+}
+
+/// Every day's test, ignored.
+macro_rules! ignored_day_tests {
+    ($($days:tt)*) => {
+        day_tests!(#[ignore = "needs real puzzle inputs, see the top of the file"] $($days)*);
+    };
+}
+
+aoc_2024_core::with_days!(ignored_day_tests);
@@ -0,0 +1,438 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{self, Display},
+    sync::Mutex,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use aoc_2024_core::day::Day;
+
+use crate::session::SessionToken;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2024;
+
+pub const OFFLINE_ENV_VAR: &str = "AOC_OFFLINE";
+
+pub const USER_AGENT: &str = concat!(
+    "github.com/chfanghr/aoc-2024 v",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub retry_after: Option<Duration>,
+    pub body: String,
+}
+
+/// Sends a request and hands back whatever the server answered, error
+/// statuses included. `Err` is reserved for requests that never got an answer.
+pub trait Transport {
+    fn send(&self, request: &Request) -> anyhow::Result<Response>;
+}
+
+#[derive(Debug)]
+pub struct UreqTransport {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqTransport {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl Transport for UreqTransport {
+    fn send(&self, request: &Request) -> anyhow::Result<Response> {
+        let method = match request.method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        let ureq_request = request
+            .headers
+            .iter()
+            .fold(self.agent.request(method, &request.url), |r, (k, v)| {
+                r.set(k, v)
+            });
+        let result = match &request.body {
+            Some(body) => ureq_request.send_string(body),
+            None => ureq_request.call(),
+        };
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            retry_after: response
+                .header("Retry-After")
+                .and_then(|secs| secs.trim().parse().ok())
+                .map(Duration::from_secs),
+            body: response.into_string()?,
+        })
+    }
+}
+
+/// adventofcode.com answered with a status other than 2xx.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpError {
+    pub url: String,
+    pub status: u16,
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} responded with HTTP {}", self.url, self.status)
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// A request was attempted while offline mode is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Offline {
+    pub url: String,
+}
+
+impl Display for Offline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "refusing to fetch {} in offline mode", self.url)
+    }
+}
+
+impl std::error::Error for Offline {}
+
+/// Whether `AOC_OFFLINE` is set to anything but an empty string, `0`,
+/// `false`, `no` or `off`.
+pub fn offline_from_env() -> bool {
+    env::var(OFFLINE_ENV_VAR).is_ok_and(|value| is_truthy(&value))
+}
+
+fn is_truthy(value: &str) -> bool {
+    !matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "false" | "no" | "off"
+    )
+}
+
+/// The one way this crate talks to adventofcode.com.
+///
+/// Requests are spaced at least `min_interval` apart, carry the session
+/// cookie and a User-Agent identifying this tool, and 429/5xx responses are
+/// retried with exponential backoff (or the server's `Retry-After`).
+#[derive(Debug)]
+pub struct AocClient<T = UreqTransport> {
+    transport: T,
+    session: SessionToken,
+    base_url: String,
+    min_interval: Duration,
+    max_retries: u32,
+    backoff: Duration,
+    offline: bool,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl AocClient {
+    pub fn new(session: SessionToken) -> Self {
+        Self::with_transport(session, UreqTransport::default())
+    }
+}
+
+impl<T: Transport> AocClient<T> {
+    pub fn with_transport(session: SessionToken, transport: T) -> Self {
+        Self {
+            transport,
+            session,
+            base_url: BASE_URL.to_owned(),
+            min_interval: Duration::from_secs(5),
+            max_retries: 3,
+            backoff: Duration::from_secs(2),
+            offline: offline_from_env(),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn get(&self, path: &str) -> anyhow::Result<String> {
+        self.send(Method::Get, path, None)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let body = form
+            .iter()
+            .map(|(k, v)| format!("{}={}", form_encode(k), form_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+        self.send(Method::Post, path, Some(body))
+    }
+
+    fn send(&self, method: Method, path: &str, body: Option<String>) -> anyhow::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        if self.offline {
+            return Err(Offline { url }.into());
+        }
+
+        let mut headers = vec![
+            ("User-Agent", USER_AGENT.to_owned()),
+            ("Cookie", format!("session={}", self.session.token)),
+        ];
+        if body.is_some() {
+            headers.push((
+                "Content-Type",
+                "application/x-www-form-urlencoded".to_owned(),
+            ));
+        }
+        let request = Request {
+            method,
+            url,
+            headers,
+            body,
+        };
+
+        let mut attempt = 0;
+        loop {
+            self.throttle();
+            let response = self.transport.send(&request)?;
+
+            let is_retryable = response.status == 429 || (500..600).contains(&response.status);
+            if (200..300).contains(&response.status) {
+                return Ok(response.body);
+            } else if !is_retryable || attempt >= self.max_retries {
+                return Err(HttpError {
+                    url: request.url,
+                    status: response.status,
+                }
+                .into());
+            }
+
+            sleep(
+                response
+                    .retry_after
+                    .unwrap_or(self.backoff * 2u32.pow(attempt)),
+            );
+            attempt += 1;
+        }
+    }
+
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(elapsed) = last_request.map(|instant| instant.elapsed()) {
+            if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+                sleep(remaining);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl<T: Transport> AocClient<T> {
+    /// The puzzle input of `day` for the session's account.
+    pub fn input(&self, day: Day) -> anyhow::Result<String> {
+        self.get(&format!("/{YEAR}/day/{}/input", day.number()))
+    }
+
+    /// Stars earned per day, read off the event calendar. Days without stars
+    /// are left out.
+    pub fn stars(&self) -> anyhow::Result<BTreeMap<Day, u8>> {
+        Ok(parse_calendar_stars(&self.get(&format!("/{YEAR}"))?))
+    }
+}
+
+/// The calendar links every day with an `aria-label` like `Day 3, two stars`
+/// (or just `Day 3` before the first star).
+fn parse_calendar_stars(html: &str) -> BTreeMap<Day, u8> {
+    html.split("aria-label=\"Day ")
+        .skip(1)
+        .filter_map(|label| {
+            let label = &label[..label.find('"')?];
+            let (day, stars) = label.split_once(", ")?;
+            let stars = match stars {
+                "one star" => 1,
+                "two stars" => 2,
+                _ => return None,
+            };
+            Some((day.parse().ok()?, stars))
+        })
+        .collect()
+}
+
+fn form_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_owned(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Replays canned responses and records the requests it was given.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct MockTransport {
+    pub responses: Mutex<std::collections::VecDeque<Response>>,
+    pub requests: Mutex<Vec<Request>>,
+}
+
+#[cfg(test)]
+impl MockTransport {
+    pub fn new(responses: impl IntoIterator<Item = (u16, &'static str)>) -> Self {
+        Self {
+            responses: Mutex::new(
+                responses
+                    .into_iter()
+                    .map(|(status, body)| Response {
+                        status,
+                        retry_after: None,
+                        body: body.to_owned(),
+                    })
+                    .collect(),
+            ),
+            requests: Mutex::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Transport for MockTransport {
+    fn send(&self, request: &Request) -> anyhow::Result<Response> {
+        self.requests.lock().unwrap().push(request.clone());
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| anyhow::anyhow!("no more canned responses"))
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn mock_client(
+    responses: impl IntoIterator<Item = (u16, &'static str)>,
+) -> AocClient<MockTransport> {
+    let session = SessionToken {
+        token: "53cr3t".to_owned(),
+        source: crate::session::TokenSource::Flag,
+    };
+    AocClient::with_transport(session, MockTransport::new(responses))
+        .min_interval(Duration::ZERO)
+        .retries(2, Duration::ZERO)
+        .offline(false)
+}
+
+#[test]
+fn tags_requests() {
+    let client = mock_client([(200, "ok")]);
+    assert_eq!(client.input(Day::new(1)).unwrap(), "ok");
+
+    let requests = client.transport.requests.lock().unwrap();
+    assert_eq!(requests[0].url, "https://adventofcode.com/2024/day/1/input");
+    assert!(requests[0]
+        .headers
+        .contains(&("User-Agent", USER_AGENT.to_owned())));
+    assert!(requests[0]
+        .headers
+        .contains(&("Cookie", "session=53cr3t".to_owned())));
+}
+
+#[test]
+fn retries_rate_limits_and_server_errors() {
+    let client = mock_client([(429, ""), (502, ""), (200, "ok")]);
+    assert_eq!(client.get("/").unwrap(), "ok");
+
+    let client = mock_client([(500, ""), (500, ""), (500, ""), (200, "ok")]);
+    let err = client.get("/").unwrap_err();
+    assert_eq!(err.downcast_ref::<HttpError>().unwrap().status, 500);
+
+    let client = mock_client([(404, ""), (200, "ok")]);
+    let err = client.get("/").unwrap_err();
+    assert_eq!(err.downcast_ref::<HttpError>().unwrap().status, 404);
+    assert_eq!(client.transport.requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn throttles_requests() {
+    let client = mock_client([(200, ""), (200, "")]).min_interval(Duration::from_millis(50));
+    let start = Instant::now();
+    client.get("/").unwrap();
+    client.post_form("/", &[("answer", "1 2")]).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    let requests = client.transport.requests.lock().unwrap();
+    assert_eq!(requests[1].body.as_deref(), Some("answer=1+2"));
+}
+
+#[test]
+fn offline_mode() {
+    let client = mock_client([(200, "ok")]).offline(true);
+    let err = client.get("/2024/day/1/input").unwrap_err();
+    assert!(err.downcast_ref::<Offline>().is_some());
+    assert!(client.transport.requests.lock().unwrap().is_empty());
+
+    assert!(is_truthy("1"));
+    assert!(is_truthy("yes"));
+    assert!(!is_truthy(""));
+    assert!(!is_truthy("Off"));
+}
+
+#[test]
+fn calendar_stars() {
+    let html = r#"<a aria-label="Day 1, two stars" href="/2024/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 2, one star" href="/2024/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 3" href="/2024/day/3" class="calendar-day3">"#;
+    let client = mock_client([(200, html)]);
+
+    assert_eq!(
+        BTreeMap::from([(Day::new(1), 2), (Day::new(2), 1)]),
+        client.stars().unwrap()
+    );
+    assert_eq!(
+        "https://adventofcode.com/2024",
+        client.transport.requests.lock().unwrap()[0].url
+    );
+}
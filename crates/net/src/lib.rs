@@ -0,0 +1,4 @@
+//! Talking to adventofcode.com: the session token and the HTTP client.
+
+pub mod aoc_client;
+pub mod session;
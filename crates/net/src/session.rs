@@ -0,0 +1,165 @@
+use std::{
+    env,
+    fmt::{self, Debug, Display},
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    Flag,
+    Env,
+    File(PathBuf),
+    Keyring,
+}
+
+impl Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::Flag => write!(f, "--session flag"),
+            TokenSource::Env => write!(f, "{SESSION_ENV_VAR} environment variable"),
+            TokenSource::File(path) => write!(f, "{}", path.display()),
+            TokenSource::Keyring => write!(f, "OS keyring"),
+        }
+    }
+}
+
+/// The adventofcode.com session cookie, together with where it was found.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionToken {
+    pub token: String,
+    pub source: TokenSource,
+}
+
+impl Debug for SessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionToken")
+            .field("token", &"<redacted>")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// None of the token sources yielded a session token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingSessionToken {
+    pub token_file: Option<PathBuf>,
+}
+
+impl Display for MissingSessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no session token found")
+    }
+}
+
+impl std::error::Error for MissingSessionToken {}
+
+/// `$XDG_CONFIG_HOME/aoc-2024/session`, falling back to `~/.config` (or
+/// `%APPDATA%` on Windows) when `XDG_CONFIG_HOME` is not set.
+pub fn default_token_file() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+    Some(config_dir.join("aoc-2024").join("session"))
+}
+
+/// Looks for the session token in the `--session` flag, the `AOC_SESSION`
+/// environment variable, the token file and, if the `keyring` feature is
+/// enabled, the OS keyring, in that order.
+pub fn resolve(flag: Option<&str>, token_file: Option<&Path>) -> anyhow::Result<SessionToken> {
+    let token_file = token_file
+        .map(Path::to_path_buf)
+        .or_else(default_token_file);
+
+    if let Some(token) = flag.and_then(non_empty) {
+        return Ok(SessionToken {
+            token,
+            source: TokenSource::Flag,
+        });
+    }
+
+    if let Some(token) = env::var(SESSION_ENV_VAR)
+        .ok()
+        .as_deref()
+        .and_then(non_empty)
+    {
+        return Ok(SessionToken {
+            token,
+            source: TokenSource::Env,
+        });
+    }
+
+    if let Some(token_file) = &token_file {
+        if let Some(token) = read_token_file(token_file)? {
+            return Ok(SessionToken {
+                token,
+                source: TokenSource::File(token_file.clone()),
+            });
+        }
+    }
+
+    #[cfg(feature = "keyring")]
+    if let Some(token) = keyring::lookup()? {
+        return Ok(SessionToken {
+            token,
+            source: TokenSource::Keyring,
+        });
+    }
+
+    Err(MissingSessionToken { token_file }.into())
+}
+
+fn read_token_file(path: &Path) -> anyhow::Result<Option<String>> {
+    match read_to_string(path) {
+        Ok(contents) => Ok(non_empty(&contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(anyhow!("failed to read {}: {}", path.display(), err)),
+    }
+}
+
+fn non_empty(token: &str) -> Option<String> {
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_owned())
+}
+
+#[cfg(feature = "keyring")]
+mod keyring {
+    const SERVICE: &str = "aoc-2024";
+    const USER: &str = "session";
+
+    pub fn lookup() -> anyhow::Result<Option<String>> {
+        match ::keyring::Entry::new(SERVICE, USER)?.get_password() {
+            Ok(token) => Ok(super::non_empty(&token)),
+            Err(::keyring::Error::NoEntry) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[test]
+fn flag_takes_precedence() {
+    let token = resolve(Some(" abc\n"), None).unwrap();
+    assert_eq!(token.token, "abc");
+    assert_eq!(token.source, TokenSource::Flag);
+    assert!(!format!("{token:?}").contains("abc"));
+}
+
+#[test]
+fn token_file() {
+    let path = env::temp_dir().join(format!("aoc-2024-session-{}", std::process::id()));
+
+    std::fs::write(&path, "0123abcd\n").unwrap();
+    assert_eq!(read_token_file(&path).unwrap(), Some("0123abcd".to_owned()));
+
+    std::fs::write(&path, "\n").unwrap();
+    assert_eq!(read_token_file(&path).unwrap(), None);
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read_token_file(&path).unwrap(), None);
+}